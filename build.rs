@@ -0,0 +1,573 @@
+// Compiles the checked-in IANA tzdata source (tzdata/tzdata.zi, in zic input format)
+// into the tables of the `tzdb` feature. Only what is needed for 1970 through 9999 is
+// kept: the local time type in effect on 1/1/1970, explicit transitions until the last
+// rules of each zone take over, and those last rules as a POSIX TZ footer.
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
+
+const SOURCE: &str = "tzdata/tzdata.zi";
+const SECONDS_PER_DAY: i64 = 86_400;
+const MAX_TIMESTAMP: i64 = 253_402_300_800;
+const MAX_YEAR: i64 = 9999;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    if env::var_os("CARGO_FEATURE_TZDB").is_none() {
+        return;
+    }
+    let source = fs::read_to_string(SOURCE).expect("tzdata source");
+    let database = Database::parse(&source);
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("tzdb.rs");
+    fs::write(out, database.generate()).unwrap();
+}
+
+// Seconds since midnight, with zic's w (wall), s (standard) and u/g/z (universal) suffixes
+#[derive(Clone, Copy, PartialEq)]
+enum Clock {
+    Wall,
+    Standard,
+    Universal,
+}
+
+#[derive(Clone, Copy)]
+struct Time {
+    seconds: i64,
+    clock: Clock,
+}
+
+#[derive(Clone, Copy)]
+enum DaySpec {
+    Day(i64),
+    Last(i64),
+    OnOrAfter(i64, i64),
+    OnOrBefore(i64, i64),
+}
+
+struct Rule {
+    from: i64,
+    to: i64,
+    month: i64,
+    day: DaySpec,
+    at: Time,
+    save: i64,
+    letter: String,
+}
+
+enum ZoneRules {
+    Fixed(i64),
+    Named(String),
+}
+
+struct Until {
+    year: i64,
+    month: i64,
+    day: DaySpec,
+    at: Time,
+}
+
+struct ZoneLine {
+    stdoff: i64,
+    rules: ZoneRules,
+    format: String,
+    until: Option<Until>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct LocalTimeType {
+    offset: i64,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+struct Footer {
+    standard: LocalTimeType,
+    // (daylight saving time, start, start time, end, end time)
+    daylight_saving: Option<(LocalTimeType, String, i64, String, i64)>,
+}
+
+struct Zone {
+    transitions: Vec<(i64, usize)>,
+    local_time_types: Vec<LocalTimeType>,
+    footer: Footer,
+}
+
+struct Database {
+    version: String,
+    rules: HashMap<String, Vec<Rule>>,
+    zones: BTreeMap<String, Vec<ZoneLine>>,
+    links: BTreeMap<String, String>,
+}
+
+// Unique prefixes as in zic, matched case-insensitively
+fn lookup(word: &str, names: &[&str]) -> i64 {
+    let word = word.to_ascii_lowercase();
+    let matches: Vec<_> = names.iter().enumerate().filter(|(_, name)| name.to_ascii_lowercase().starts_with(&word)).collect();
+    match matches.as_slice() {
+        [(index, _)] => *index as i64,
+        _ => panic!("ambiguous or unknown name {}", word),
+    }
+}
+
+const MONTHS: &[&str] = &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+// Sunday is 0 like POSIX TZ strings
+const WEEKDAYS: &[&str] = &["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+fn parse_month(word: &str) -> i64 {
+    lookup(word, MONTHS) + 1
+}
+
+fn parse_year(word: &str, from: i64) -> i64 {
+    match word {
+        "o" | "only" => from,
+        "ma" | "max" => i64::MAX,
+        "mi" | "min" => i64::MIN,
+        year => year.parse().unwrap(),
+    }
+}
+
+fn parse_day(word: &str) -> DaySpec {
+    if let Some(weekday) = word.strip_prefix("last") {
+        DaySpec::Last(lookup(weekday, WEEKDAYS))
+    } else if let Some((weekday, day)) = word.split_once(">=") {
+        DaySpec::OnOrAfter(lookup(weekday, WEEKDAYS), day.parse().unwrap())
+    } else if let Some((weekday, day)) = word.split_once("<=") {
+        DaySpec::OnOrBefore(lookup(weekday, WEEKDAYS), day.parse().unwrap())
+    } else {
+        DaySpec::Day(word.parse().unwrap())
+    }
+}
+
+fn parse_seconds(word: &str) -> i64 {
+    let (sign, word) = match word.strip_prefix('-') {
+        Some(word) => (-1, word),
+        None => (1, word),
+    };
+    let seconds = word.split(':').zip(&[3_600, 60, 1]).map(|(part, unit)| part.parse::<i64>().unwrap() * unit).sum::<i64>();
+    sign * seconds
+}
+
+fn parse_time(word: &str) -> Time {
+    let (word, clock) = match word.as_bytes().last() {
+        Some(b'w') => (&word[..word.len() - 1], Clock::Wall),
+        Some(b's') => (&word[..word.len() - 1], Clock::Standard),
+        Some(b'u') | Some(b'g') | Some(b'z') => (&word[..word.len() - 1], Clock::Universal),
+        _ => (word, Clock::Wall),
+    };
+    Time { seconds: if word == "-" { 0 } else { parse_seconds(word) }, clock }
+}
+
+// Days since 1/1/1970, from http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn year_of(timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    let mut year = days * 400 / 146_097 + 1970;
+    while days_from_civil(year, 1, 1) > days {
+        year -= 1;
+    }
+    while days_from_civil(year + 1, 1, 1) <= days {
+        year += 1;
+    }
+    year
+}
+
+fn weekday(days: i64) -> i64 {
+    // The unix epoch was a Thursday
+    (days + 4).rem_euclid(7)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let next = if month == 12 { days_from_civil(year + 1, 1, 1) } else { days_from_civil(year, month + 1, 1) };
+    next - days_from_civil(year, month, 1)
+}
+
+fn day_of(year: i64, month: i64, day: DaySpec) -> i64 {
+    match day {
+        DaySpec::Day(day) => days_from_civil(year, month, day),
+        DaySpec::Last(weekday_) => {
+            let last = days_from_civil(year, month, days_in_month(year, month));
+            last - (weekday(last) - weekday_).rem_euclid(7)
+        },
+        DaySpec::OnOrAfter(weekday_, day) => {
+            let days = days_from_civil(year, month, day);
+            days + (weekday_ - weekday(days)).rem_euclid(7)
+        },
+        DaySpec::OnOrBefore(weekday_, day) => {
+            let days = days_from_civil(year, month, day);
+            days - (weekday(days) - weekday_).rem_euclid(7)
+        },
+    }
+}
+
+// Transition instant of a time given in local time before the transition
+fn to_universal(days: i64, at: Time, stdoff: i64, save: i64) -> i64 {
+    let local = days * SECONDS_PER_DAY + at.seconds;
+    match at.clock {
+        Clock::Wall => local - stdoff - save,
+        Clock::Standard => local - stdoff,
+        Clock::Universal => local,
+    }
+}
+
+// Numeric abbreviations for %z, e.g. "-03", "+0530" or "+053045"
+fn numeric_abbreviation(offset: i64) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let (hours, minutes, seconds) = (offset.abs() / 3_600, offset.abs() / 60 % 60, offset.abs() % 60);
+    if seconds != 0 {
+        format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds)
+    } else if minutes != 0 {
+        format!("{}{:02}{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}", sign, hours)
+    }
+}
+
+fn local_time_type(line: &ZoneLine, save: i64, letter: &str) -> LocalTimeType {
+    let offset = line.stdoff + save;
+    let format = match line.format.split_once('/') {
+        Some((standard, _)) if save == 0 => standard,
+        Some((_, daylight_saving)) => daylight_saving,
+        None => &line.format,
+    };
+    let abbreviation = format.replace("%s", letter).replace("%z", &numeric_abbreviation(offset));
+    LocalTimeType { offset, is_dst: save != 0, abbreviation }
+}
+
+// Rules on the first weekday on or after a day other than 1, 8, 15 or 22 are
+// rewritten to an earlier weekday and a later time, as zic does
+fn posix_rule(rule: &Rule, time: i64) -> (String, i64) {
+    let (weekday, day) = match rule.day {
+        DaySpec::Day(day) => {
+            let non_leap_ordinal = days_from_civil(2001, rule.month, day) - days_from_civil(2001, 1, 1) + 1;
+            return (format!("RuleDay::Julian1({})", non_leap_ordinal), time);
+        },
+        DaySpec::Last(weekday) => return (format!("RuleDay::MonthWeekDay {{ month: {}, week: 5, weekday: {} }}", rule.month, WEEKDAY_CONSTANTS[weekday as usize]), time),
+        DaySpec::OnOrBefore(weekday, day) if day == days_in_month(2001, rule.month) && rule.month != 2 => {
+            return (format!("RuleDay::MonthWeekDay {{ month: {}, week: 5, weekday: {} }}", rule.month, WEEKDAY_CONSTANTS[weekday as usize]), time);
+        },
+        DaySpec::OnOrBefore(weekday, day) => (weekday, day - 6),
+        DaySpec::OnOrAfter(weekday, day) => (weekday, day),
+    };
+    // Week 5 means the last week, so the rewritten day must be in one of the first four
+    assert!((1..=28).contains(&day), "unsupported POSIX rule day");
+    let shift = (day - 1) % 7;
+    let week = (day - 1) / 7 + 1;
+    let weekday = (weekday - shift).rem_euclid(7);
+    (
+        format!("RuleDay::MonthWeekDay {{ month: {}, week: {}, weekday: {} }}", rule.month, week, WEEKDAY_CONSTANTS[weekday as usize]),
+        time + shift * SECONDS_PER_DAY,
+    )
+}
+
+const WEEKDAY_CONSTANTS: &[&str] = &["Weekday::SUNDAY", "Weekday::MONDAY", "Weekday::TUESDAY", "Weekday::WEDNESDAY", "Weekday::THURSDAY", "Weekday::FRIDAY", "Weekday::SATURDAY"];
+
+impl Database {
+    fn parse(source: &str) -> Self {
+        let version = source.lines().next().and_then(|line| line.strip_prefix("# version ")).expect("tzdata version").to_string();
+        let mut database = Self { version, rules: HashMap::new(), zones: BTreeMap::new(), links: BTreeMap::new() };
+        let mut zone = None;
+        for line in source.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => (),
+                [comment, ..] if comment.starts_with('#') => (),
+                ["R", name, from, to, _, month, day, at, save, letter] => {
+                    let from = parse_year(from, 0);
+                    database.rules.entry(name.to_string()).or_default().push(Rule {
+                        from,
+                        to: parse_year(to, from),
+                        month: parse_month(month),
+                        day: parse_day(day),
+                        at: parse_time(at),
+                        save: parse_seconds(save.trim_end_matches(&['s', 'd'][..])),
+                        letter: if *letter == "-" { String::new() } else { letter.to_string() },
+                    });
+                },
+                ["L", target, name] => {
+                    database.links.insert(name.to_string(), target.to_string());
+                },
+                ["Z", name, rest @ ..] => {
+                    database.zones.insert(name.to_string(), vec![Self::parse_zone_line(rest)]);
+                    zone = Some(name.to_string());
+                },
+                rest => {
+                    let name = zone.as_ref().expect("zone continuation line");
+                    database.zones.get_mut(name).unwrap().push(Self::parse_zone_line(rest));
+                },
+            }
+        }
+        database
+    }
+
+    fn parse_zone_line(fields: &[&str]) -> ZoneLine {
+        let rules = match fields[1] {
+            "-" => ZoneRules::Fixed(0),
+            rules if rules.starts_with(|c: char| c.is_ascii_digit() || c == '-') => ZoneRules::Fixed(parse_seconds(rules)),
+            rules => ZoneRules::Named(rules.to_string()),
+        };
+        let until = fields.get(3).map(|year| Until {
+            year: year.parse().unwrap(),
+            month: fields.get(4).map_or(1, |month| parse_month(month)),
+            day: fields.get(5).map_or(DaySpec::Day(1), |day| parse_day(day)),
+            at: fields.get(6).map_or(Time { seconds: 0, clock: Clock::Wall }, |at| parse_time(at)),
+        });
+        ZoneLine { stdoff: parse_seconds(fields[0]), rules, format: fields[2].to_string(), until }
+    }
+
+    fn rules(&self, line: &ZoneLine) -> &[Rule] {
+        match &line.rules {
+            ZoneRules::Named(name) => self.rules.get(name).unwrap_or_else(|| panic!("unknown rule {}", name)),
+            ZoneRules::Fixed(_) => &[],
+        }
+    }
+
+    // The last year whose transitions may not follow the footer
+    fn last_explicit_year(&self, lines: &[ZoneLine], start: i64) -> i64 {
+        let start_year = year_of(start.max(0));
+        self.rules(lines.last().unwrap())
+            .iter()
+            .filter(|rule| rule.to >= start_year)
+            .map(|rule| if rule.to == i64::MAX { rule.from } else { rule.to })
+            .fold(start_year, i64::max)
+            + 1
+    }
+
+    fn compile(&self, lines: &[ZoneLine]) -> Zone {
+        let mut transitions: Vec<(i64, LocalTimeType)> = Vec::new();
+        let mut push = |timestamp: i64, local_time_type: LocalTimeType| {
+            while matches!(transitions.last(), Some(&(last, _)) if last >= timestamp) {
+                transitions.pop();
+            }
+            transitions.push((timestamp, local_time_type));
+        };
+
+        let mut start = i64::MIN;
+        let mut limit = MAX_TIMESTAMP;
+        let mut state = (0, String::new());
+        for (index, line) in lines.iter().enumerate() {
+            if index == lines.len() - 1 {
+                let last_year = self.last_explicit_year(lines, start).min(MAX_YEAR);
+                limit = days_from_civil(last_year + 1, 1, 1) * SECONDS_PER_DAY;
+            }
+            let rules = self.rules(line);
+            if let ZoneRules::Fixed(save) = line.rules {
+                state = (save, String::new());
+                push(start, local_time_type(line, save, ""));
+            } else {
+                let first_year = if start == i64::MIN { rules.iter().map(|rule| rule.from).min().unwrap() } else { year_of(start) } - 1;
+                let last_year = line.until.as_ref().map_or(year_of(limit), |until| until.year) + 1;
+                let mut events: Vec<(i64, &Rule)> = Vec::new();
+                for year in first_year..=last_year {
+                    for rule in rules.iter().filter(|rule| rule.from <= year && year <= rule.to) {
+                        events.push((day_of(year, rule.month, rule.day), rule));
+                    }
+                }
+                events.sort_by_key(|&(days, rule)| days * SECONDS_PER_DAY + rule.at.seconds);
+
+                // Before any rule applies, the letter of the earliest standard time rule is used
+                let first_letter = events.iter().find(|(_, rule)| rule.save == 0).map_or(String::new(), |(_, rule)| rule.letter.clone());
+                state = (0, first_letter);
+                let mut events = events.into_iter().peekable();
+                while let Some(&(days, rule)) = events.peek() {
+                    if to_universal(days, rule.at, line.stdoff, state.0) > start {
+                        break;
+                    }
+                    state = (rule.save, rule.letter.clone());
+                    events.next();
+                }
+                push(start, local_time_type(line, state.0, &state.1));
+                for (days, rule) in events {
+                    let timestamp = to_universal(days, rule.at, line.stdoff, state.0);
+                    let until = line.until.as_ref().map_or(limit, |until| to_universal(day_of(until.year, until.month, until.day), until.at, line.stdoff, state.0));
+                    if timestamp >= until.min(limit) {
+                        break;
+                    }
+                    state = (rule.save, rule.letter.clone());
+                    push(timestamp, local_time_type(line, state.0, &state.1));
+                }
+            }
+            start = match &line.until {
+                Some(until) => to_universal(day_of(until.year, until.month, until.day), until.at, line.stdoff, state.0),
+                None => break,
+            };
+        }
+
+        // Like zic, a transition that happens no later in local time than the one before it
+        // replaces that one, e.g. a zone line and a rule both changing at 2:00 standard time
+        let mut merged: Vec<(i64, LocalTimeType)> = Vec::with_capacity(transitions.len());
+        for (timestamp, local_time_type) in transitions {
+            if let [.., (_, before), (previous, last)] = merged.as_mut_slice() {
+                if timestamp + last.offset <= *previous + before.offset {
+                    *last = local_time_type;
+                    continue;
+                }
+            }
+            merged.push((timestamp, local_time_type));
+        }
+        let transitions = merged;
+
+        // Only the local time type in effect on 1/1/1970 and later ones are kept
+        let initial = transitions.iter().rev().find(|&&(timestamp, _)| timestamp <= 0).unwrap().1.clone();
+        let mut local_time_types = vec![initial.clone()];
+        let mut kept = Vec::new();
+        let mut current = initial;
+        for (timestamp, local_time_type) in transitions {
+            if timestamp <= 0 || timestamp >= MAX_TIMESTAMP || timestamp >= limit || local_time_type == current {
+                continue;
+            }
+            let index = match local_time_types.iter().position(|existing| *existing == local_time_type) {
+                Some(index) => index,
+                None => {
+                    local_time_types.push(local_time_type.clone());
+                    local_time_types.len() - 1
+                },
+            };
+            kept.push((timestamp, index));
+            current = local_time_type;
+        }
+        let footer = self.footer(lines.last().unwrap(), &state);
+        Zone { transitions: kept, local_time_types, footer }
+    }
+
+    fn footer(&self, line: &ZoneLine, state: &(i64, String)) -> Footer {
+        let rules: Vec<&Rule> = self.rules(line).iter().filter(|rule| rule.to == i64::MAX).collect();
+        match rules.as_slice() {
+            [] => Footer { standard: local_time_type(line, state.0, &state.1), daylight_saving: None },
+            [first, second] => {
+                let (start, end) = if first.save != 0 { (first, second) } else { (second, first) };
+                assert!(start.save != 0 && end.save == 0, "unsupported final rules");
+                let standard = local_time_type(line, 0, &end.letter);
+                let daylight_saving = local_time_type(line, start.save, &start.letter);
+                // POSIX TZ transition times are in the local time before the transition
+                let start_time = match start.at.clock {
+                    Clock::Wall | Clock::Standard => start.at.seconds,
+                    Clock::Universal => start.at.seconds + line.stdoff,
+                };
+                let end_time = match end.at.clock {
+                    Clock::Wall => end.at.seconds,
+                    Clock::Standard => end.at.seconds + start.save,
+                    Clock::Universal => end.at.seconds + line.stdoff + start.save,
+                };
+                let (start_day, start_time) = posix_rule(start, start_time);
+                let (end_day, end_time) = posix_rule(end, end_time);
+                Footer { standard, daylight_saving: Some((daylight_saving, start_day, start_time, end_day, end_time)) }
+            },
+            _ => panic!("unsupported final rules"),
+        }
+    }
+
+    fn generate(&self) -> String {
+        let mut names: Vec<(&str, usize)> = self.zones.keys().enumerate().map(|(index, name)| (name.as_str(), index)).collect();
+        for (name, target) in &self.links {
+            let mut target = target;
+            while let Some(next) = self.links.get(target) {
+                target = next;
+            }
+            let index = self.zones.keys().position(|zone| zone == target).unwrap_or_else(|| panic!("unknown link target {}", target));
+            names.push((name, index));
+        }
+        let (seeds, slots) = perfect_hash(&names);
+
+        let mut out = String::new();
+        writeln!(out, "// Generated by build.rs from tzdata {}", self.version).unwrap();
+        writeln!(out, "const VERSION: &str = {:?};", self.version).unwrap();
+        writeln!(out, "const SEEDS: &[u32] = &{:?};", seeds).unwrap();
+        writeln!(out, "const NAMES: &[(&str, u16)] = &[").unwrap();
+        for &slot in &slots {
+            writeln!(out, "    ({:?}, {}),", names[slot].0, names[slot].1).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out, "const ZONES: &[StaticTimeZone] = &[").unwrap();
+        for (name, lines) in &self.zones {
+            let zone = self.compile(lines);
+            let times: Vec<i64> = zone.transitions.iter().map(|&(timestamp, _)| timestamp).collect();
+            let types: Vec<usize> = zone.transitions.iter().map(|&(_, index)| index).collect();
+            writeln!(out, "    StaticTimeZone {{").unwrap();
+            writeln!(out, "        name: {:?},", name).unwrap();
+            writeln!(out, "        transition_times: &{:?},", times).unwrap();
+            writeln!(out, "        transition_types: &{:?},", types).unwrap();
+            let local_time_types: Vec<String> = zone.local_time_types.iter().map(generate_local_time_type).collect();
+            writeln!(out, "        local_time_types: &[{}],", local_time_types.join(", ")).unwrap();
+            let footer = &zone.footer;
+            let daylight_saving = match &footer.daylight_saving {
+                Some((local_time_type, start, start_time, end, end_time)) => format!(
+                    "Some(DaylightSavingTime {{ local_time_type: {}, start: {}, start_time: {}, end: {}, end_time: {} }})",
+                    generate_local_time_type(local_time_type),
+                    start,
+                    start_time,
+                    end,
+                    end_time
+                ),
+                None => String::from("None"),
+            };
+            writeln!(out, "        footer: PosixTimeZone::new({}, {}),", generate_local_time_type(&footer.standard), daylight_saving).unwrap();
+            writeln!(out, "    }},").unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        out
+    }
+}
+
+fn generate_local_time_type(local_time_type: &LocalTimeType) -> String {
+    format!("local_time_type({}, {}, b{:?})", local_time_type.offset, local_time_type.is_dst, local_time_type.abbreviation)
+}
+
+// Must match `hash` in src/tzdb.rs
+fn hash(name: &str, seed: u32) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325 ^ seed as u64;
+    for &byte in name.as_bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+// Hash and displace: each bucket of names gets the first seed that moves all of
+// its names into free slots
+#[allow(clippy::manual_div_ceil)] // div_ceil is MSRV 1.73
+fn perfect_hash(names: &[(&str, usize)]) -> (Vec<u32>, Vec<usize>) {
+    let bucket_count = (names.len() + 3) / 4;
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); bucket_count];
+    for (index, &(name, _)) in names.iter().enumerate() {
+        buckets[(hash(name, 0) % bucket_count as u64) as usize].push(index);
+    }
+    let mut order: Vec<usize> = (0..bucket_count).collect();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut seeds = vec![0; bucket_count];
+    let mut slots: Vec<Option<usize>> = vec![None; names.len()];
+    for bucket in order {
+        if buckets[bucket].is_empty() {
+            continue;
+        }
+        'seed: for seed in 1.. {
+            let mut taken = Vec::new();
+            for &index in &buckets[bucket] {
+                let slot = (hash(names[index].0, seed) % names.len() as u64) as usize;
+                if slots[slot].is_some() || taken.contains(&slot) {
+                    continue 'seed;
+                }
+                taken.push(slot);
+            }
+            for (&index, slot) in buckets[bucket].iter().zip(taken) {
+                slots[slot] = Some(index);
+            }
+            seeds[bucket] = seed;
+            break;
+        }
+    }
+    (seeds, slots.into_iter().map(Option::unwrap).collect())
+}
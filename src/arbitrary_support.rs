@@ -0,0 +1,24 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+use super::{generators, util::Weekday, UnixTimestamp};
+
+// Any timestamp in range, with edge cases such as leap days, new years and `MAX` more
+// likely. Exhausted input gives 1/1/1970.
+impl<'a> Arbitrary<'a> for UnixTimestamp {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(generators::timestamp(u8::arbitrary(u)?, u64::arbitrary(u)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (9, Some(9))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Weekday {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&Weekday::ALL).copied()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
@@ -0,0 +1,79 @@
+// Conversions between `UnixTimestamp` and the Arrow arrays used by columnar formats such as
+// Parquet. `UnixTimestamp` has the layout of an `i64`, so `TimestampSecondArray` values are
+// moved or borrowed without copying them one at a time.
+
+use core::{fmt, mem::ManuallyDrop, slice};
+use arrow_array::{Array, Date32Array, TimestampSecondArray};
+use arrow_buffer::ScalarBuffer;
+use super::{util, UnixTimestamp};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowTimestampError {
+    // The index of the first null value
+    NullValue(usize),
+    // Seconds out of range of `UnixTimestamp`
+    OutOfRange(i64),
+}
+
+impl fmt::Display for ArrowTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NullValue(index) => write!(f, "null timestamp at index {}", index),
+            Self::OutOfRange(seconds) => write!(f, "timestamp seconds {} are out of range of UnixTimestamp", seconds),
+        }
+    }
+}
+
+impl std::error::Error for ArrowTimestampError {}
+
+fn check_nulls(array: &dyn Array) -> Result<(), ArrowTimestampError> {
+    match array.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => Err(ArrowTimestampError::NullValue((0..nulls.len()).find(|&index| nulls.is_null(index)).unwrap_or(0))),
+        _ => Ok(()),
+    }
+}
+
+// Takes ownership of the allocation without copying, as a UTC timestamp array
+pub fn timestamp_array(timestamps: Vec<UnixTimestamp>) -> TimestampSecondArray {
+    let mut timestamps = ManuallyDrop::new(timestamps);
+    // SAFETY: `UnixTimestamp` is `repr(transparent)` over `i64`, so the allocation has the
+    // layout of a `Vec<i64>` with the same length and capacity
+    let values = unsafe { Vec::from_raw_parts(timestamps.as_mut_ptr() as *mut i64, timestamps.len(), timestamps.capacity()) };
+    TimestampSecondArray::new(ScalarBuffer::from(values), None).with_timezone_utc()
+}
+
+// Copies the slice in one go, as a UTC timestamp array
+pub fn timestamp_array_from_slice(timestamps: &[UnixTimestamp]) -> TimestampSecondArray {
+    timestamp_array(timestamps.to_vec())
+}
+
+// The UTC dates of the timestamps
+pub fn date32_array(timestamps: &[UnixTimestamp]) -> Date32Array {
+    Date32Array::from_iter_values(timestamps.iter().map(|timestamp| (timestamp.unix_timestamp() / util::SECONDS_PER_DAY) as i32))
+}
+
+// Borrows the values without copying them, after checking that none are null or out of
+// range. The time zone of the array is ignored as its values are always from the epoch
+// in UTC.
+pub fn timestamps(array: &TimestampSecondArray) -> Result<&[UnixTimestamp], ArrowTimestampError> {
+    check_nulls(array)?;
+    let values: &[i64] = array.values();
+    if let Some(&seconds) = values.iter().find(|&&seconds| UnixTimestamp::checked_from_unix_timestamp(seconds).is_none()) {
+        return Err(ArrowTimestampError::OutOfRange(seconds));
+    }
+    // SAFETY: `UnixTimestamp` is `repr(transparent)` over `i64` and every value is in range
+    Ok(unsafe { slice::from_raw_parts(values.as_ptr() as *const UnixTimestamp, values.len()) })
+}
+
+// Midnight UTC of each date
+pub fn timestamps_from_date32(array: &Date32Array) -> Result<Vec<UnixTimestamp>, ArrowTimestampError> {
+    check_nulls(array)?;
+    array
+        .values()
+        .iter()
+        .map(|&days| {
+            let seconds = i64::from(days) * util::SECONDS_PER_DAY;
+            UnixTimestamp::checked_from_unix_timestamp(seconds).ok_or(ArrowTimestampError::OutOfRange(seconds))
+        })
+        .collect()
+}
@@ -0,0 +1,142 @@
+use super::{
+    util::{self, Weekday, WeekdaySet},
+    HolidayCalendar,
+    UnixTimestamp,
+};
+
+const MAX_DAY: i64 = UnixTimestamp::MAX.unix_timestamp() / util::SECONDS_PER_DAY;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar<'a> {
+    weekend: WeekdaySet,
+    holidays: Option<HolidayCalendar<'a>>,
+}
+
+impl<'a> BusinessCalendar<'a> {
+    pub const fn new(weekend: WeekdaySet) -> Self {
+        Self { weekend, holidays: None }
+    }
+
+    pub const fn with_holidays(self, holidays: HolidayCalendar<'a>) -> Self {
+        Self { holidays: Some(holidays), ..self }
+    }
+
+    pub const fn weekend(&self) -> WeekdaySet {
+        self.weekend
+    }
+
+    pub const fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.contains(weekday)
+    }
+
+    pub const fn holidays(&self) -> Option<HolidayCalendar<'a>> {
+        self.holidays
+    }
+
+    // Bitmask of workdays indexed from Monday
+    const fn workdays(&self) -> u8 {
+        self.weekend.complement().bits()
+    }
+}
+
+impl Default for BusinessCalendar<'_> {
+    fn default() -> Self {
+        Self::new(WeekdaySet::WEEKEND)
+    }
+}
+
+// Days are counted since the unix epoch, which was a Thursday
+const fn is_workday(workdays: u8, day: i64) -> bool {
+    workdays >> ((day + 3) % 7) & 1 != 0
+}
+
+// Number of workdays in `start..end`
+fn count_workdays(workdays: u8, start: i64, end: i64) -> i64 {
+    let weeks = (end - start) / 7;
+    let partial = (start + weeks * 7..end).filter(|&day| is_workday(workdays, day)).count() as i64;
+    weeks * workdays.count_ones() as i64 + partial
+}
+
+// The day `count` workdays away from `day`, skipping whole weeks at once
+fn offset_workdays(workdays: u8, day: i64, count: i64) -> i64 {
+    let (step, count) = (count.signum(), count.abs());
+    let per_week = workdays.count_ones() as i64;
+    let weeks = (count - 1) / per_week;
+    let mut remaining = count - weeks * per_week;
+    let mut day = day + step * weeks * 7;
+    while remaining > 0 {
+        day += step;
+        remaining -= is_workday(workdays, day) as i64;
+    }
+    day
+}
+
+// Number of distinct holidays observed on a workday in `start..end`
+fn count_holidays(calendar: &BusinessCalendar<'_>, start: i64, end: i64) -> i64 {
+    let holidays = match calendar.holidays {
+        Some(holidays) if start < end => holidays,
+        _ => return 0,
+    };
+    let year_of = |day: i64| UnixTimestamp::from_unix_timestamp(day * util::SECONDS_PER_DAY).unwrap().to_year_month_day().0;
+    let mut count = 0;
+    for year in year_of(start)..=year_of(end - 1) {
+        let mut last = None;
+        for (date, _) in holidays.holidays(year) {
+            let day = date.unix_timestamp() / util::SECONDS_PER_DAY;
+            if last != Some(day) && start <= day && day < end && is_workday(calendar.workdays(), day) {
+                count += 1;
+            }
+            last = Some(day);
+        }
+    }
+    count
+}
+
+pub(super) fn is_business_day(timestamp: UnixTimestamp, calendar: &BusinessCalendar<'_>) -> bool {
+    let is_holiday = match calendar.holidays {
+        Some(holidays) => holidays.is_holiday(timestamp),
+        None => false,
+    };
+    !calendar.is_weekend(timestamp.weekday()) && !is_holiday
+}
+
+// Holidays are accounted for by repeatedly stepping over as many workdays as
+// there were holidays in the previous step, until a step contains none
+pub(super) fn add_business_days(timestamp: UnixTimestamp, days: i64, calendar: &BusinessCalendar<'_>) -> Option<UnixTimestamp> {
+    if days == 0 {
+        return Some(timestamp);
+    }
+    if calendar.workdays() == 0 {
+        return None;
+    }
+    let mut day = timestamp.unix_timestamp() / util::SECONDS_PER_DAY;
+    let mut remaining = days;
+    while remaining != 0 {
+        // Every workday is at least a day apart, so this also guards against overflow
+        if remaining.unsigned_abs() > MAX_DAY as u64 {
+            return None;
+        }
+        let target = offset_workdays(calendar.workdays(), day, remaining);
+        if !(0..=MAX_DAY).contains(&target) {
+            return None;
+        }
+        let holidays = if remaining > 0 {
+            count_holidays(calendar, day + 1, target + 1)
+        } else {
+            count_holidays(calendar, target, day)
+        };
+        day = target;
+        remaining = remaining.signum() * holidays;
+    }
+    UnixTimestamp::checked_from_unix_timestamp(day * util::SECONDS_PER_DAY + timestamp.seconds_since_midnight())
+}
+
+// Business days in the half-open range of dates `start..end`, negated if `end` is before `start`
+pub(super) fn business_days_between(start: UnixTimestamp, end: UnixTimestamp, calendar: &BusinessCalendar<'_>) -> i64 {
+    if end < start {
+        return -business_days_between(end, start, calendar);
+    }
+    let start = start.unix_timestamp() / util::SECONDS_PER_DAY;
+    let end = end.unix_timestamp() / util::SECONDS_PER_DAY;
+    count_workdays(calendar.workdays(), start, end) - count_holidays(calendar, start, end)
+}
@@ -0,0 +1,21 @@
+use bytemuck::{CheckedBitPattern, NoUninit, Zeroable};
+use super::UnixTimestamp;
+
+// `UnixTimestamp` is not `Pod`, as that would let any `i64` be cast to one unchecked.
+// `bytemuck::checked` casts validate the range instead, and casts to bytes or `i64` are free.
+
+// SAFETY: all zeros is 1/1/1970, `UnixTimestamp::MIN`
+unsafe impl Zeroable for UnixTimestamp {}
+
+// SAFETY: `UnixTimestamp` is `repr(transparent)` over `i64`, which has no padding
+unsafe impl NoUninit for UnixTimestamp {}
+
+// SAFETY: `UnixTimestamp` is `repr(transparent)` over `i64`, and only values in its range
+// are valid
+unsafe impl CheckedBitPattern for UnixTimestamp {
+    type Bits = i64;
+
+    fn is_valid_bit_pattern(bits: &i64) -> bool {
+        UnixTimestamp::checked_from_unix_timestamp(*bits).is_some()
+    }
+}
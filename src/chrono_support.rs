@@ -0,0 +1,73 @@
+use core::convert::TryFrom;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use super::{result::TimestampResult, util, UnixTimestamp};
+
+const CHRONO_WEEKDAYS: [chrono::Weekday; 7] = [
+    chrono::Weekday::Mon,
+    chrono::Weekday::Tue,
+    chrono::Weekday::Wed,
+    chrono::Weekday::Thu,
+    chrono::Weekday::Fri,
+    chrono::Weekday::Sat,
+    chrono::Weekday::Sun,
+];
+
+// 1/1/1970 counted from 1/1/0001 as day 1, like `Datelike::num_days_from_ce`
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+impl From<UnixTimestamp> for DateTime<Utc> {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        DateTime::from_timestamp(timestamp.unix_timestamp(), 0).expect("Timestamp out of range of chrono")
+    }
+}
+
+impl From<UnixTimestamp> for NaiveDateTime {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        DateTime::<Utc>::from(timestamp).naive_utc()
+    }
+}
+
+// The UTC date of the timestamp
+impl From<UnixTimestamp> for NaiveDate {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        DateTime::<Utc>::from(timestamp).date_naive()
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date_time: DateTime<Tz>) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp(date_time.timestamp()).into_result()
+    }
+}
+
+// Read as UTC
+impl TryFrom<NaiveDateTime> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date_time: NaiveDateTime) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp(date_time.and_utc().timestamp()).into_result()
+    }
+}
+
+// Midnight UTC of the date
+impl TryFrom<NaiveDate> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp((date.num_days_from_ce() as i64 - UNIX_EPOCH_DAYS_FROM_CE) * util::SECONDS_PER_DAY).into_result()
+    }
+}
+
+impl From<util::Weekday> for chrono::Weekday {
+    fn from(weekday: util::Weekday) -> Self {
+        CHRONO_WEEKDAYS[weekday.number_days_from_monday() as usize]
+    }
+}
+
+impl From<chrono::Weekday> for util::Weekday {
+    fn from(weekday: chrono::Weekday) -> Self {
+        util::Weekday::ALL[weekday.num_days_from_monday() as usize]
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use core::cell::Cell;
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, PoisonError};
+use super::UnixTimestamp;
+
+// A source of the current time, so code that reads the clock can be tested
+// deterministically. `#![no_std]` targets can implement it over an RTC.
+pub trait Clock {
+    fn now(&self) -> UnixTimestamp;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> UnixTimestamp {
+        (**self).now()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> UnixTimestamp {
+        (**self).now()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> UnixTimestamp {
+        (**self).now()
+    }
+}
+
+// Reads `SystemTime`, like `UnixTimestamp::now`
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> UnixTimestamp {
+        UnixTimestamp::now()
+    }
+}
+
+// Reads CLOCK_REALTIME_COARSE, like `UnixTimestamp::now_coarse`
+#[cfg(all(feature = "libc", unix))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CoarseClock;
+
+#[cfg(all(feature = "libc", unix))]
+impl Clock for CoarseClock {
+    fn now(&self) -> UnixTimestamp {
+        UnixTimestamp::now_coarse()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedClock(UnixTimestamp);
+
+impl FixedClock {
+    pub const fn new(timestamp: UnixTimestamp) -> Self {
+        Self(timestamp)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> UnixTimestamp {
+        self.0
+    }
+}
+
+// Only moves when set or advanced. It can be shared between threads under `std`.
+#[derive(Debug)]
+pub struct MockClock {
+    #[cfg(feature = "std")]
+    timestamp: Mutex<UnixTimestamp>,
+    #[cfg(not(feature = "std"))]
+    timestamp: Cell<UnixTimestamp>,
+}
+
+impl MockClock {
+    pub fn new(timestamp: UnixTimestamp) -> Self {
+        Self { timestamp: timestamp.into() }
+    }
+
+    pub fn set(&self, timestamp: UnixTimestamp) {
+        self.update(|_| timestamp);
+    }
+
+    // Saturates at `UnixTimestamp::MIN` and `UnixTimestamp::MAX`
+    pub fn advance(&self, seconds: i64) {
+        self.update(|timestamp| timestamp.saturating_add(seconds));
+    }
+
+    #[cfg(feature = "std")]
+    fn update(&self, f: impl FnOnce(UnixTimestamp) -> UnixTimestamp) {
+        let mut timestamp = self.timestamp.lock().unwrap_or_else(PoisonError::into_inner);
+        *timestamp = f(*timestamp);
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn update(&self, f: impl FnOnce(UnixTimestamp) -> UnixTimestamp) {
+        self.timestamp.set(f(self.timestamp.get()));
+    }
+}
+
+impl Clock for MockClock {
+    #[cfg(feature = "std")]
+    fn now(&self) -> UnixTimestamp {
+        *self.timestamp.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn now(&self) -> UnixTimestamp {
+        self.timestamp.get()
+    }
+}
@@ -0,0 +1,284 @@
+// Byte level encodings of `UnixTimestamp` for binary formats, without `std` or `alloc`.
+// Encoders write into a caller provided buffer and return the number of bytes written,
+// and decoders return the timestamp and the number of bytes read.
+
+use core::fmt;
+use super::UnixTimestamp;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodecError {
+    BufferTooSmall,
+    UnexpectedEof,
+    // Not a timestamp of the expected format
+    InvalidFormat,
+    InvalidNanos(u32),
+    // A valid encoding of a time out of range of `UnixTimestamp`, or of the format
+    OutOfRange(i64),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall => f.write_str("buffer too small for encoded timestamp"),
+            Self::UnexpectedEof => f.write_str("unexpected end of encoded timestamp"),
+            Self::InvalidFormat => f.write_str("invalid timestamp encoding"),
+            Self::InvalidNanos(nanos) => write!(f, "timestamp nanoseconds {} are not from 0 to 999999999", nanos),
+            Self::OutOfRange(seconds) => write!(f, "timestamp seconds {} are out of range", seconds),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodecError {}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CodecError> {
+        let bytes = self.position.checked_add(len).and_then(|end| self.bytes.get(self.position..end)).ok_or(CodecError::UnexpectedEof)?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, CodecError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, CodecError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, CodecError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, CodecError> {
+        let mut buffer = [0; 8];
+        buffer.copy_from_slice(self.take(8)?);
+        Ok(u64::from_be_bytes(buffer))
+    }
+}
+
+fn write(buffer: &mut [u8], bytes: &[u8]) -> Result<usize, CodecError> {
+    buffer.get_mut(..bytes.len()).ok_or(CodecError::BufferTooSmall)?.copy_from_slice(bytes);
+    Ok(bytes.len())
+}
+
+fn from_seconds(seconds: i64) -> Result<UnixTimestamp, CodecError> {
+    UnixTimestamp::checked_from_unix_timestamp(seconds).ok_or(CodecError::OutOfRange(seconds))
+}
+
+// The timestamp extension type -1 of MessagePack
+// (https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type)
+pub mod msgpack {
+    use super::{from_seconds, write, CodecError, Cursor};
+    use crate::UnixTimestamp;
+
+    pub const EXT_TYPE: i8 = -1;
+    pub const MAX_ENCODED_LEN: usize = 15;
+
+    const FIXEXT4: u8 = 0xd6;
+    const FIXEXT8: u8 = 0xd7;
+    const EXT8: u8 = 0xc7;
+    const TYPE: u8 = EXT_TYPE as u8;
+
+    // Uses timestamp 32 until 2106, timestamp 64 until 2514 and timestamp 96 after, so
+    // 6, 10 or 15 bytes
+    pub fn encode(timestamp: UnixTimestamp, buffer: &mut [u8]) -> Result<usize, CodecError> {
+        let seconds = timestamp.unix_timestamp() as u64;
+        if seconds >> 32 == 0 {
+            let [a, b, c, d] = (seconds as u32).to_be_bytes();
+            write(buffer, &[FIXEXT4, TYPE, a, b, c, d])
+        } else if seconds >> 34 == 0 {
+            let [a, b, c, d, e, f, g, h] = seconds.to_be_bytes();
+            write(buffer, &[FIXEXT8, TYPE, a, b, c, d, e, f, g, h])
+        } else {
+            let [a, b, c, d, e, f, g, h] = seconds.to_be_bytes();
+            write(buffer, &[EXT8, 12, TYPE, 0, 0, 0, 0, a, b, c, d, e, f, g, h])
+        }
+    }
+
+    // Reads any of the three forms. Nanoseconds are truncated.
+    pub fn decode(bytes: &[u8]) -> Result<(UnixTimestamp, usize), CodecError> {
+        let mut cursor = Cursor::new(bytes);
+        let (nanos, seconds) = match cursor.u8()? {
+            FIXEXT4 => (0, i64::from(read_type(&mut cursor)?.u32()?)),
+            FIXEXT8 => {
+                let data = read_type(&mut cursor)?.u64()?;
+                ((data >> 34) as u32, (data & 0x3_ffff_ffff) as i64)
+            },
+            EXT8 if cursor.u8()? == 12 => {
+                let cursor = read_type(&mut cursor)?;
+                (cursor.u32()?, cursor.u64()? as i64)
+            },
+            _ => return Err(CodecError::InvalidFormat),
+        };
+        if nanos >= 1_000_000_000 {
+            return Err(CodecError::InvalidNanos(nanos));
+        }
+        Ok((from_seconds(seconds)?, cursor.position))
+    }
+
+    fn read_type<'a, 'b>(cursor: &'a mut Cursor<'b>) -> Result<&'a mut Cursor<'b>, CodecError> {
+        if cursor.u8()? == TYPE {
+            Ok(cursor)
+        } else {
+            Err(CodecError::InvalidFormat)
+        }
+    }
+}
+
+// CBOR tag 0, an RFC 3339 string, and tag 1, seconds from the epoch
+// (https://www.rfc-editor.org/rfc/rfc8949.html#section-3.4)
+pub mod cbor {
+    use core::convert::TryFrom;
+    use super::{from_seconds, write, CodecError, Cursor};
+    use crate::{rfc3339::parse_rfc3339, UnixTimestamp};
+
+    pub const MAX_EPOCH_LEN: usize = 10;
+    pub const RFC3339_LEN: usize = 22;
+
+    const UNSIGNED: u8 = 0;
+    const NEGATIVE: u8 = 1;
+    const TEXT: u8 = 3;
+    const TAG: u8 = 6;
+    const SIMPLE: u8 = 7;
+
+    const TAG_RFC3339: u64 = 0;
+    const TAG_EPOCH: u64 = 1;
+
+    // The head of a data item with the shortest argument, 1 to 9 bytes
+    fn head(major: u8, argument: u64, bytes: &mut [u8; 9]) -> &[u8] {
+        let major = major << 5;
+        let [a, b, c, d, e, f, g, h] = argument.to_be_bytes();
+        *bytes = [major, a, b, c, d, e, f, g, h];
+        if argument < 24 {
+            bytes[0] |= argument as u8;
+            &bytes[..1]
+        } else if argument >> 8 == 0 {
+            bytes[0] |= 24;
+            bytes[1] = h;
+            &bytes[..2]
+        } else if argument >> 16 == 0 {
+            bytes[0] |= 25;
+            bytes[1..3].copy_from_slice(&[g, h]);
+            &bytes[..3]
+        } else if argument >> 32 == 0 {
+            bytes[0] |= 26;
+            bytes[1..5].copy_from_slice(&[e, f, g, h]);
+            &bytes[..5]
+        } else {
+            bytes[0] |= 27;
+            &bytes[..]
+        }
+    }
+
+    // The major type, additional information and argument of a data item. Indefinite
+    // lengths aren't supported.
+    fn read_head(cursor: &mut Cursor<'_>) -> Result<(u8, u8, u64), CodecError> {
+        let initial = cursor.u8()?;
+        let info = initial & 0x1f;
+        let argument = match info {
+            0..=23 => u64::from(info),
+            24 => u64::from(cursor.u8()?),
+            25 => u64::from(cursor.u16()?),
+            26 => u64::from(cursor.u32()?),
+            27 => cursor.u64()?,
+            _ => return Err(CodecError::InvalidFormat),
+        };
+        Ok((initial >> 5, info, argument))
+    }
+
+    // Tag 1 with the shortest unsigned integer, 1 to 10 bytes
+    pub fn encode_epoch(timestamp: UnixTimestamp, buffer: &mut [u8]) -> Result<usize, CodecError> {
+        let mut bytes = [0; 9];
+        let tag = write(buffer, head(TAG, TAG_EPOCH, &mut bytes))?;
+        let value = write(&mut buffer[tag..], head(UNSIGNED, timestamp.unix_timestamp() as u64, &mut bytes))?;
+        Ok(tag + value)
+    }
+
+    // Tag 0 with a UTC string such as 2021-03-14T07:00:00Z, 22 bytes. `UnixTimestamp::MAX`
+    // has a five digit year, so it is out of range.
+    pub fn encode_rfc3339(timestamp: UnixTimestamp, buffer: &mut [u8]) -> Result<usize, CodecError> {
+        let (year, month, day) = timestamp.to_year_month_day();
+        if year > 9999 {
+            return Err(CodecError::OutOfRange(timestamp.unix_timestamp()));
+        }
+        let seconds = timestamp.seconds_since_midnight() as u32;
+        let mut bytes = *b"\xc0\x74YYYY-MM-DDTHH:MM:SSZ";
+        write_digits(&mut bytes[2..6], year.into());
+        write_digits(&mut bytes[7..9], month.into());
+        write_digits(&mut bytes[10..12], day.into());
+        write_digits(&mut bytes[13..15], seconds / 3_600);
+        write_digits(&mut bytes[16..18], seconds / 60 % 60);
+        write_digits(&mut bytes[19..21], seconds % 60);
+        write(buffer, &bytes)
+    }
+
+    fn write_digits(bytes: &mut [u8], mut n: u32) {
+        for digit in bytes.iter_mut().rev() {
+            *digit = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+    }
+
+    // Reads either tag. Tag 1 may hold an integer or a half, single or double precision
+    // float, and fractional seconds are truncated like RFC 3339 strings in tag 0.
+    pub fn decode(bytes: &[u8]) -> Result<(UnixTimestamp, usize), CodecError> {
+        let mut cursor = Cursor::new(bytes);
+        let timestamp = match read_head(&mut cursor)? {
+            (TAG, _, TAG_RFC3339) => match read_head(&mut cursor)? {
+                (TEXT, _, len) => {
+                    let text = cursor.take(usize::try_from(len).map_err(|_| CodecError::UnexpectedEof)?)?;
+                    parse_rfc3339(text).ok_or(CodecError::InvalidFormat)?.timestamp()
+                },
+                _ => return Err(CodecError::InvalidFormat),
+            },
+            (TAG, _, TAG_EPOCH) => match read_head(&mut cursor)? {
+                (UNSIGNED, _, value) => from_seconds(i64::try_from(value).unwrap_or(i64::MAX))?,
+                (NEGATIVE, _, value) => return Err(CodecError::OutOfRange(i64::try_from(value).map_or(i64::MIN, |value| -1 - value))),
+                (SIMPLE, 25, bits) => from_float(half_to_f64(bits as u16))?,
+                (SIMPLE, 26, bits) => from_float(f32::from_bits(bits as u32).into())?,
+                (SIMPLE, 27, bits) => from_float(f64::from_bits(bits))?,
+                _ => return Err(CodecError::InvalidFormat),
+            },
+            _ => return Err(CodecError::InvalidFormat),
+        };
+        Ok((timestamp, cursor.position))
+    }
+
+    fn from_float(value: f64) -> Result<UnixTimestamp, CodecError> {
+        if value.is_nan() {
+            return Err(CodecError::InvalidFormat);
+        }
+        // Rounds toward negative infinity without `f64::floor`, which needs `std`. The cast
+        // saturates for infinities and values out of range of i64.
+        let seconds = value as i64;
+        from_seconds(if seconds as f64 > value { seconds.saturating_sub(1) } else { seconds })
+    }
+
+    fn half_to_f64(bits: u16) -> f64 {
+        let exponent = u64::from((bits >> 10) & 0x1f);
+        let mantissa = f64::from(bits & 0x3ff);
+        let value = match exponent {
+            0 => mantissa * f64::from_bits((1023 - 24) << 52),
+            31 if mantissa == 0.0 => f64::INFINITY,
+            31 => f64::NAN,
+            _ => (mantissa + 1024.0) * f64::from_bits((exponent + 1023 - 25) << 52),
+        };
+        if bits & 0x8000 == 0 {
+            value
+        } else {
+            -value
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::{util, UnixTimestamp};
+
+// Random timestamps for the property testing crates, built from random integers so each
+// crate only has to supply those. About a quarter are edge cases.
+
+const EDGE_CASES: u8 = 6;
+const DAYS: u64 = (UnixTimestamp::MAX.unix_timestamp() / util::SECONDS_PER_DAY) as u64;
+
+#[cfg(any(feature = "quickcheck", feature = "arbitrary"))]
+pub(super) fn timestamp(choice: u8, value: u64) -> UnixTimestamp {
+    match choice % 4 {
+        0 => edge_case(choice / 4, value),
+        _ => uniform(value),
+    }
+}
+
+pub(super) fn uniform(value: u64) -> UnixTimestamp {
+    UnixTimestamp::from_unix_timestamp((value % (UnixTimestamp::MAX.unix_timestamp() as u64 + 1)) as i64).unwrap()
+}
+
+pub(super) fn edge_case(kind: u8, value: u64) -> UnixTimestamp {
+    match kind % EDGE_CASES {
+        0 => UnixTimestamp::MIN,
+        1 => UnixTimestamp::MAX,
+        // A second either side of midnight on 1/1 of 1971 through 9999
+        2 => {
+            let year = 1971 + (value % 8_029) as u16;
+            UnixTimestamp::from_year_month_day(year, 1, 1).unwrap().saturating_add((value / 8_029 % 3) as i64 - 1)
+        },
+        // Any time on 2/29 of a leap year, moving past century years that aren't leap years
+        3 => {
+            let year = 1972 + (value % 2_007) as u16 * 4;
+            let year = if util::is_leap_year(year) { year } else { year + 4 };
+            UnixTimestamp::from_year_month_day(year, 2, 29).unwrap().saturating_add((value / 2_007 % util::SECONDS_PER_DAY as u64) as i64)
+        },
+        // Midnight on 3/1 of a century year, or the second before, with or without a leap day
+        4 => {
+            let year = 2000 + (value % 80) as u16 * 100;
+            UnixTimestamp::from_year_month_day(year, 3, 1).unwrap().saturating_sub((value / 80 % 2) as i64)
+        },
+        // The last second of any day
+        _ => UnixTimestamp::from_unix_timestamp(((value % DAYS + 1) * util::SECONDS_PER_DAY as u64 - 1) as i64).unwrap(),
+    }
+}
@@ -0,0 +1,216 @@
+use super::{
+    util::{self, Weekday},
+    UnixTimestamp,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HolidayDate {
+    Fixed { month: u8, day: u8 },
+    // `nth` is 1-based, so the 3rd Monday of January is `nth: 3`
+    NthWeekday { month: u8, weekday: Weekday, nth: u8 },
+    LastWeekday { month: u8, weekday: Weekday },
+    EasterOffset(i16),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Observance {
+    Actual,
+    // Saturday is observed on Friday and Sunday on Monday
+    NearestWeekday,
+    // Saturday and Sunday are observed on Monday
+    NextWeekday,
+    // Like `NextWeekday`, but also skips days taken by another holiday in the calendar
+    NextFreeWeekday,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    name: &'static str,
+    date: HolidayDate,
+    observance: Observance,
+}
+
+impl HolidayRule {
+    pub const fn new(name: &'static str, date: HolidayDate) -> Self {
+        Self { name, date, observance: Observance::Actual }
+    }
+
+    pub const fn with_observance(self, observance: Observance) -> Self {
+        Self { observance, ..self }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn date(&self) -> HolidayDate {
+        self.date
+    }
+
+    pub const fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    // The date before any observance shifting
+    pub const fn date_in_year(&self, year: u16) -> Option<UnixTimestamp> {
+        match self.date {
+            HolidayDate::Fixed { month, day } => UnixTimestamp::checked_from_year_month_day(year, month, day),
+            HolidayDate::NthWeekday { month, weekday, nth } => {
+                if nth == 0 {
+                    return None;
+                }
+                let first = match UnixTimestamp::checked_from_year_month_day(year, month, 1) {
+                    Some(first) => first,
+                    None => return None,
+                };
+                let day = 1 + first.weekday().days_until(weekday) + 7 * (nth as u64 - 1);
+                if day > util::days_in_year_month(year, month) as u64 {
+                    return None;
+                }
+                UnixTimestamp::checked_from_year_month_day(year, month, day as u8)
+            },
+            HolidayDate::LastWeekday { month, weekday } => {
+                let last_day = util::days_in_year_month(year, month);
+                let last = match UnixTimestamp::checked_from_year_month_day(year, month, last_day) {
+                    Some(last) => last,
+                    None => return None,
+                };
+                last.checked_sub(last.weekday().days_since(weekday) as i64 * util::SECONDS_PER_DAY)
+            },
+            HolidayDate::EasterOffset(days) => {
+                if year < 1970 || year > 9999 {
+                    return None;
+                }
+                util::easter_sunday(year).checked_add(days as i64 * util::SECONDS_PER_DAY)
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HolidayCalendar<'a> {
+    rules: &'a [HolidayRule],
+}
+
+// Observed dates of every rule in a year, computed in one pass on the stack
+type ObservedDates = [Option<UnixTimestamp>; HolidayCalendar::MAX_RULES];
+
+impl<'a> HolidayCalendar<'a> {
+    pub const MAX_RULES: usize = 64;
+
+    // Panics if there are more than `MAX_RULES` rules
+    pub const fn new(rules: &'a [HolidayRule]) -> Self {
+        assert!(rules.len() <= Self::MAX_RULES, "too many holiday rules");
+        Self { rules }
+    }
+
+    pub const fn rules(&self) -> &'a [HolidayRule] {
+        self.rules
+    }
+
+    // Observed dates may fall in an adjacent year, e.g. January 1st on a Saturday
+    pub fn observed_date(&self, index: usize, year: u16) -> Option<UnixTimestamp> {
+        if index >= self.rules.len() {
+            return None;
+        }
+        self.observed_dates(year)[index]
+    }
+
+    // Rules are observed in index order, so a day is taken by any other holiday
+    // that actually falls on it, or by an earlier rule that is observed on it
+    fn observed_dates(&self, year: u16) -> ObservedDates {
+        let mut observed: ObservedDates = [None; Self::MAX_RULES];
+        for (index, rule) in self.rules.iter().enumerate() {
+            let is_taken = |date: UnixTimestamp| {
+                self.rules.iter().enumerate().any(|(other, rule)| other != index && rule.date_in_year(year) == Some(date))
+                    || observed[..index].contains(&Some(date))
+            };
+            observed[index] = rule.date_in_year(year).and_then(|date| {
+                let weekday = date.weekday();
+                match rule.observance {
+                    Observance::Actual => Some(date),
+                    Observance::NearestWeekday if weekday == Weekday::SATURDAY => date.checked_sub(util::SECONDS_PER_DAY),
+                    Observance::NearestWeekday if weekday == Weekday::SUNDAY => date.checked_add(util::SECONDS_PER_DAY),
+                    Observance::NearestWeekday => Some(date),
+                    Observance::NextWeekday => next_weekday(date),
+                    Observance::NextFreeWeekday => {
+                        let mut date = next_weekday(date)?;
+                        while is_taken(date) {
+                            date = next_weekday(date.checked_add(util::SECONDS_PER_DAY)?)?;
+                        }
+                        Some(date)
+                    },
+                }
+            });
+        }
+        observed
+    }
+
+    pub fn holiday(&self, timestamp: UnixTimestamp) -> Option<&'a HolidayRule> {
+        let date = timestamp.midnight();
+        let (year, month, _) = date.to_year_month_day();
+        let years = match month {
+            1 => year.saturating_sub(1)..=year,
+            12 => year..=year.saturating_add(1),
+            _ => year..=year,
+        };
+        for year in years {
+            let observed = self.observed_dates(year);
+            if let Some(index) = observed[..self.rules.len()].iter().position(|&observed| observed == Some(date)) {
+                return Some(&self.rules[index]);
+            }
+        }
+        None
+    }
+
+    pub fn is_holiday(&self, timestamp: UnixTimestamp) -> bool {
+        self.holiday(timestamp).is_some()
+    }
+
+    // Observed holidays falling in `year`, in chronological order
+    pub fn holidays(&self, year: u16) -> Holidays<'a> {
+        Holidays { calendar: *self, year, last: None }
+    }
+}
+
+const fn next_weekday(date: UnixTimestamp) -> Option<UnixTimestamp> {
+    let weekday = date.weekday();
+    if weekday.days_since(Weekday::SATURDAY) < 2 {
+        date.checked_add(weekday.days_until(Weekday::MONDAY) as i64 * util::SECONDS_PER_DAY)
+    } else {
+        Some(date)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Holidays<'a> {
+    calendar: HolidayCalendar<'a>,
+    year: u16,
+    last: Option<(UnixTimestamp, usize)>,
+}
+
+impl<'a> Iterator for Holidays<'a> {
+    type Item = (UnixTimestamp, &'a HolidayRule);
+
+    // Selects the smallest (date, rule) after the last one yielded, which keeps
+    // the iterator allocation free for the handful of rules a calendar has
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut next: Option<(UnixTimestamp, usize)> = None;
+        for year in self.year.saturating_sub(1)..=self.year.saturating_add(1) {
+            let observed = self.calendar.observed_dates(year);
+            for (index, &date) in observed[..self.calendar.rules.len()].iter().enumerate() {
+                let date = match date {
+                    Some(date) if date.to_year_month_day().0 == self.year => date,
+                    _ => continue,
+                };
+                let key = (date, index);
+                if Some(key) > self.last && (next.is_none() || Some(key) < next) {
+                    next = Some(key);
+                }
+            }
+        }
+        let (date, index) = next?;
+        self.last = next;
+        Some((date, &self.calendar.rules[index]))
+    }
+}
@@ -0,0 +1,252 @@
+use alloc::vec::{self, Vec};
+use core::{
+    cmp,
+    iter::{self, FromIterator},
+    ops::{BitAnd, BitOr, Range, Sub},
+    slice,
+};
+use super::UnixTimestamp;
+
+// Sorted, disjoint, half-open intervals where no two intervals are adjacent,
+// so every set of timestamps has exactly one representation
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Range<UnixTimestamp>>,
+}
+
+impl IntervalSet {
+    pub const fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    pub fn from_range(range: Range<UnixTimestamp>) -> Self {
+        let mut intervals = Vec::new();
+        push_coalesced(&mut intervals, range);
+        Self { intervals }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn intervals(&self) -> &[Range<UnixTimestamp>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Range<UnixTimestamp>> {
+        self.intervals.iter()
+    }
+
+    pub fn bounds(&self) -> Option<Range<UnixTimestamp>> {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some(first), Some(last)) => Some(first.start..last.end),
+            _ => None,
+        }
+    }
+
+    pub fn total_seconds(&self) -> i64 {
+        self.intervals.iter().map(|range| range.end.unix_timestamp() - range.start.unix_timestamp()).sum()
+    }
+
+    pub fn contains(&self, timestamp: UnixTimestamp) -> bool {
+        let index = self.intervals.partition_point(|range| range.end <= timestamp); // MSRV 1.52
+        match self.intervals.get(index) {
+            Some(range) => range.start <= timestamp,
+            None => false,
+        }
+    }
+
+    pub fn overlaps(&self, range: &Range<UnixTimestamp>) -> bool {
+        if range.start >= range.end {
+            return false;
+        }
+        let index = self.intervals.partition_point(|interval| interval.end <= range.start);
+        match self.intervals.get(index) {
+            Some(interval) => interval.start < range.end,
+            None => false,
+        }
+    }
+
+    pub fn insert(&mut self, range: Range<UnixTimestamp>) {
+        if range.start >= range.end {
+            return;
+        }
+        // Intervals that overlap or touch `range` are absorbed into it
+        let low = self.intervals.partition_point(|interval| interval.end < range.start);
+        let high = self.intervals.partition_point(|interval| interval.start <= range.end);
+        let merged = if low < high {
+            cmp::min(self.intervals[low].start, range.start)..cmp::max(self.intervals[high - 1].end, range.end)
+        } else {
+            range
+        };
+        self.intervals.splice(low..high, iter::once(merged));
+    }
+
+    pub fn remove(&mut self, range: Range<UnixTimestamp>) {
+        if range.start >= range.end {
+            return;
+        }
+        let low = self.intervals.partition_point(|interval| interval.end <= range.start);
+        let high = self.intervals.partition_point(|interval| interval.start < range.end);
+        if low >= high {
+            return;
+        }
+        let left = self.intervals[low].start..range.start;
+        let right = range.end..self.intervals[high - 1].end;
+        let remainder = iter::once(left).chain(iter::once(right)).filter(|interval| interval.start < interval.end);
+        self.intervals.splice(low..high, remainder);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + other.intervals.len());
+        let (mut lhs, mut rhs) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        loop {
+            let next = match (lhs.peek(), rhs.peek()) {
+                (Some(l), Some(r)) => if l.start <= r.start { lhs.next() } else { rhs.next() },
+                (Some(_), None) => lhs.next(),
+                (None, Some(_)) => rhs.next(),
+                (None, None) => break,
+            };
+            if let Some(range) = next {
+                push_coalesced(&mut intervals, range.clone());
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (lhs, rhs) = (&self.intervals[i], &other.intervals[j]);
+            push_coalesced(&mut intervals, cmp::max(lhs.start, rhs.start)..cmp::min(lhs.end, rhs.end));
+            if lhs.end <= rhs.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for range in &self.intervals {
+            while j < other.intervals.len() && other.intervals[j].end <= range.start {
+                j += 1;
+            }
+            let mut start = range.start;
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < range.end {
+                push_coalesced(&mut intervals, start..other.intervals[k].start);
+                start = cmp::max(start, other.intervals[k].end);
+                k += 1;
+            }
+            push_coalesced(&mut intervals, start..range.end);
+            // The last subtracted interval may extend into the next range
+            j = if k > j && other.intervals[k - 1].end > range.end { k - 1 } else { k };
+        }
+        Self { intervals }
+    }
+
+    pub fn complement(&self, bounds: Range<UnixTimestamp>) -> Self {
+        let mut intervals = Vec::new();
+        let mut start = bounds.start;
+        for range in &self.intervals {
+            if range.start >= bounds.end {
+                break;
+            }
+            push_coalesced(&mut intervals, start..cmp::min(range.start, bounds.end));
+            start = cmp::max(start, range.end);
+        }
+        push_coalesced(&mut intervals, start..bounds.end);
+        Self { intervals }
+    }
+}
+
+// Appends `range` assuming it does not start before the last interval
+fn push_coalesced(intervals: &mut Vec<Range<UnixTimestamp>>, range: Range<UnixTimestamp>) {
+    if range.start >= range.end {
+        return;
+    }
+    match intervals.last_mut() {
+        Some(last) if range.start <= last.end => last.end = cmp::max(last.end, range.end),
+        _ => intervals.push(range),
+    }
+}
+
+fn coalesce(mut unsorted: Vec<Range<UnixTimestamp>>) -> Vec<Range<UnixTimestamp>> {
+    unsorted.sort_unstable_by_key(|range| range.start);
+    let mut intervals = Vec::with_capacity(unsorted.len());
+    for range in unsorted {
+        push_coalesced(&mut intervals, range);
+    }
+    intervals
+}
+
+impl From<Range<UnixTimestamp>> for IntervalSet {
+    fn from(range: Range<UnixTimestamp>) -> Self {
+        Self::from_range(range)
+    }
+}
+
+impl FromIterator<Range<UnixTimestamp>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<UnixTimestamp>>>(iter: I) -> Self {
+        Self { intervals: coalesce(iter.into_iter().collect()) }
+    }
+}
+
+impl Extend<Range<UnixTimestamp>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Range<UnixTimestamp>>>(&mut self, iter: I) {
+        let mut intervals = core::mem::take(&mut self.intervals);
+        intervals.extend(iter);
+        self.intervals = coalesce(intervals);
+    }
+}
+
+impl IntoIterator for IntervalSet {
+    type Item = Range<UnixTimestamp>;
+    type IntoIter = vec::IntoIter<Range<UnixTimestamp>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Range<UnixTimestamp>;
+    type IntoIter = slice::Iter<'a, Range<UnixTimestamp>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+impl BitOr for &IntervalSet {
+    type Output = IntervalSet;
+
+    fn bitor(self, rhs: Self) -> IntervalSet {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &IntervalSet {
+    type Output = IntervalSet;
+
+    fn bitand(self, rhs: Self) -> IntervalSet {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &IntervalSet {
+    type Output = IntervalSet;
+
+    fn sub(self, rhs: Self) -> IntervalSet {
+        self.difference(rhs)
+    }
+}
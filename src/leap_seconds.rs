@@ -0,0 +1,387 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use super::{util, UnixTimestamp};
+
+// Seconds from 1/1/1900, the epoch of NTP and of leap-seconds.list, to 1/1/1970
+#[cfg(feature = "alloc")]
+const NTP_UNIX_OFFSET: i64 = 2_208_988_800;
+// 1/6/1980 @ 12:00:00AM UTC on the TAI scale, when TAI − UTC was 19 seconds
+const GPS_EPOCH_TAI: i64 = 315_964_819;
+const SECONDS_PER_WEEK: i64 = 7 * util::SECONDS_PER_DAY;
+// Smeared clocks spread each leap second over the 24 hours from noon to noon around it,
+// as the public NTP servers of Google and AWS do
+const SMEAR_SECONDS: i64 = util::SECONDS_PER_DAY;
+
+// TAI − UTC from `timestamp` on, so a positive leap second is the 23:59:60 just before it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LeapSecond {
+    timestamp: UnixTimestamp,
+    tai_offset: i32,
+}
+
+impl LeapSecond {
+    pub const fn new(timestamp: UnixTimestamp, tai_offset: i32) -> Self {
+        Self { timestamp, tai_offset }
+    }
+
+    pub const fn timestamp(self) -> UnixTimestamp {
+        self.timestamp
+    }
+
+    pub const fn tai_offset(self) -> i32 {
+        self.tai_offset
+    }
+}
+
+const fn leap_second(year: u16, month: u8, tai_offset: i32) -> LeapSecond {
+    LeapSecond::new(UnixTimestamp::from_year_month_day(year, month, 1).unwrap(), tai_offset)
+}
+
+// From the leap-seconds.list of tzdata 2026a, which is newer than the 2025b zones embedded
+// by the `tzdb` feature. Both have the same leap seconds, and only the 2026a list extends
+// the expiry.
+const BUILTIN_LEAP_SECONDS: &[LeapSecond] = &[
+    leap_second(1972, 1, 10),
+    leap_second(1972, 7, 11),
+    leap_second(1973, 1, 12),
+    leap_second(1974, 1, 13),
+    leap_second(1975, 1, 14),
+    leap_second(1976, 1, 15),
+    leap_second(1977, 1, 16),
+    leap_second(1978, 1, 17),
+    leap_second(1979, 1, 18),
+    leap_second(1980, 1, 19),
+    leap_second(1981, 7, 20),
+    leap_second(1982, 7, 21),
+    leap_second(1983, 7, 22),
+    leap_second(1985, 7, 23),
+    leap_second(1988, 1, 24),
+    leap_second(1990, 1, 25),
+    leap_second(1991, 1, 26),
+    leap_second(1992, 7, 27),
+    leap_second(1993, 7, 28),
+    leap_second(1994, 7, 29),
+    leap_second(1996, 1, 30),
+    leap_second(1997, 7, 31),
+    leap_second(1999, 1, 32),
+    leap_second(2006, 1, 33),
+    leap_second(2009, 1, 34),
+    leap_second(2012, 7, 35),
+    leap_second(2015, 7, 36),
+    leap_second(2017, 1, 37),
+];
+
+// Leap seconds sorted by timestamp, known to be complete until `expires`.
+// Lookups past `expires` keep using the last entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LeapSecondTable<'a> {
+    leap_seconds: &'a [LeapSecond],
+    expires: UnixTimestamp,
+}
+
+impl LeapSecondTable<'static> {
+    pub const BUILTIN: Self = Self {
+        leap_seconds: BUILTIN_LEAP_SECONDS,
+        expires: UnixTimestamp::from_year_month_day(2026, 12, 28).unwrap(),
+    };
+}
+
+impl<'a> LeapSecondTable<'a> {
+    // Leap seconds must be at midnight, in order, and each change TAI − UTC by one second
+    pub const fn checked_new(leap_seconds: &'a [LeapSecond], expires: UnixTimestamp) -> Option<Self> {
+        let mut i = 0;
+        while i < leap_seconds.len() {
+            if leap_seconds[i].timestamp.seconds_since_midnight() != 0 {
+                return None;
+            }
+            if i > 0 {
+                let previous = leap_seconds[i - 1];
+                let step = leap_seconds[i].tai_offset as i64 - previous.tai_offset as i64;
+                if leap_seconds[i].timestamp.unix_timestamp() <= previous.timestamp.unix_timestamp() || (step != 1 && step != -1) {
+                    return None;
+                }
+            }
+            i += 1;
+        }
+        Some(Self { leap_seconds, expires })
+    }
+
+    pub const fn leap_seconds(&self) -> &'a [LeapSecond] {
+        self.leap_seconds
+    }
+
+    pub const fn expires(&self) -> UnixTimestamp {
+        self.expires
+    }
+
+    // TAI − UTC at `timestamp`, or None before the first entry, as UTC only kept whole
+    // seconds from TAI from 1/1/1972 on
+    pub fn tai_offset(&self, timestamp: UnixTimestamp) -> Option<i32> {
+        let index = self.leap_seconds.partition_point(|leap_second| leap_second.timestamp <= timestamp); // MSRV 1.52
+        Some(self.leap_seconds[index.checked_sub(1)?].tai_offset)
+    }
+
+    // Whether 23:59:60 happened on the day of `timestamp`
+    pub fn has_leap_second(&self, timestamp: UnixTimestamp) -> bool {
+        let next_midnight = timestamp.midnight().unix_timestamp() + util::SECONDS_PER_DAY;
+        matches!(self.leap_second_at(next_midnight), Some(index) if self.inserts(index))
+    }
+
+    fn smear_windows(&self) -> impl Iterator<Item = SmearWindow> + '_ {
+        self.leap_seconds.windows(2).map(|pair| {
+            let start = pair[1].timestamp.unix_timestamp() - SMEAR_SECONDS / 2;
+            SmearWindow {
+                start,
+                tai_start: start + pair[0].tai_offset as i64,
+                tai_length: SMEAR_SECONDS + (pair[1].tai_offset - pair[0].tai_offset) as i64,
+            }
+        })
+    }
+
+    fn leap_second_at(&self, timestamp: i64) -> Option<usize> {
+        self.leap_seconds.iter().position(|leap_second| leap_second.timestamp.unix_timestamp() == timestamp)
+    }
+
+    fn inserts(&self, index: usize) -> bool {
+        index > 0 && self.leap_seconds[index].tai_offset > self.leap_seconds[index - 1].tai_offset
+    }
+}
+
+// The smeared clock covers SMEAR_SECONDS from `start` while TAI covers `tai_length`
+struct SmearWindow {
+    start: i64,
+    tai_start: i64,
+    tai_length: i64,
+}
+
+// Seconds on the TAI scale since 1/1/1970 @ 12:00:00AM TAI, the same count as CLOCK_TAI on Linux
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaiTimestamp(i64);
+
+impl TaiTimestamp {
+    pub const fn new(seconds: i64) -> Self {
+        Self(seconds)
+    }
+
+    pub const fn seconds(self) -> i64 {
+        self.0
+    }
+
+    pub fn from_utc(timestamp: UnixTimestamp, table: &LeapSecondTable<'_>) -> Option<Self> {
+        Some(Self(timestamp.unix_timestamp() + table.tai_offset(timestamp)? as i64))
+    }
+
+    // Instants inside an inserted leap second come back as 23:59:60
+    pub fn to_utc(self, table: &LeapSecondTable<'_>) -> Option<UtcTimestamp> {
+        let leap_seconds = table.leap_seconds;
+        let index = leap_seconds.partition_point(|leap_second| leap_second.timestamp.unix_timestamp() + leap_second.tai_offset as i64 <= self.0);
+        let leap_second = leap_seconds[index.checked_sub(1)?];
+        let timestamp = self.0 - leap_second.tai_offset as i64;
+        match leap_seconds.get(index) {
+            Some(next) if timestamp >= next.timestamp.unix_timestamp() => {
+                let timestamp = UnixTimestamp::checked_from_unix_timestamp(next.timestamp.unix_timestamp() - 1)?;
+                Some(UtcTimestamp { timestamp, is_leap_second: true })
+            },
+            _ => Some(UtcTimestamp::new(UnixTimestamp::checked_from_unix_timestamp(timestamp)?)),
+        }
+    }
+
+    pub const fn to_gps(self) -> GpsTimestamp {
+        GpsTimestamp(self.0 - GPS_EPOCH_TAI)
+    }
+}
+
+// Seconds since 1/6/1980 @ 12:00:00AM UTC, which GPS time keeps 19 seconds behind TAI
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GpsTimestamp(i64);
+
+impl GpsTimestamp {
+    pub const fn new(seconds: i64) -> Self {
+        Self(seconds)
+    }
+
+    // Week numbers count from the GPS epoch without the 1024 week rollover of the broadcast value
+    pub const fn checked_from_week_time_of_week(week: u32, time_of_week: u32) -> Option<Self> {
+        if time_of_week as i64 >= SECONDS_PER_WEEK {
+            return None;
+        }
+        Some(Self(week as i64 * SECONDS_PER_WEEK + time_of_week as i64))
+    }
+
+    pub const fn seconds(self) -> i64 {
+        self.0
+    }
+
+    pub const fn week(self) -> i64 {
+        self.0.div_euclid(SECONDS_PER_WEEK)
+    }
+
+    pub const fn time_of_week(self) -> i64 {
+        self.0.rem_euclid(SECONDS_PER_WEEK)
+    }
+
+    pub const fn to_tai(self) -> TaiTimestamp {
+        TaiTimestamp(self.0 + GPS_EPOCH_TAI)
+    }
+
+    pub fn from_utc(timestamp: UnixTimestamp, table: &LeapSecondTable<'_>) -> Option<Self> {
+        Some(TaiTimestamp::from_utc(timestamp, table)?.to_gps())
+    }
+
+    pub fn to_utc(self, table: &LeapSecondTable<'_>) -> Option<UtcTimestamp> {
+        self.to_tai().to_utc(table)
+    }
+}
+
+// A UTC reading that, unlike `UnixTimestamp`, can name a leap second. 23:59:60 keeps the
+// timestamp of 23:59:59, so ordering follows the clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcTimestamp {
+    timestamp: UnixTimestamp,
+    is_leap_second: bool,
+}
+
+impl UtcTimestamp {
+    pub const fn new(timestamp: UnixTimestamp) -> Self {
+        Self { timestamp, is_leap_second: false }
+    }
+
+    // 23:59:60 of the day of `timestamp`, if the table has a leap second there
+    pub fn checked_leap_second(timestamp: UnixTimestamp, table: &LeapSecondTable<'_>) -> Option<Self> {
+        if !table.has_leap_second(timestamp) {
+            return None;
+        }
+        let timestamp = timestamp.midnight().checked_add(util::SECONDS_PER_DAY - 1)?;
+        Some(Self { timestamp, is_leap_second: true })
+    }
+
+    pub const fn timestamp(self) -> UnixTimestamp {
+        self.timestamp
+    }
+
+    pub const fn is_leap_second(self) -> bool {
+        self.is_leap_second
+    }
+
+    // 86_400 during a leap second
+    pub const fn seconds_since_midnight(self) -> i64 {
+        self.timestamp.seconds_since_midnight() + self.is_leap_second as i64
+    }
+
+    pub fn to_tai(self, table: &LeapSecondTable<'_>) -> Option<TaiTimestamp> {
+        let tai = TaiTimestamp::from_utc(self.timestamp, table)?;
+        Some(TaiTimestamp(tai.0 + self.is_leap_second as i64))
+    }
+
+    // The reading of a smeared clock at this instant, rounded down to the second
+    pub fn to_smeared(self, table: &LeapSecondTable<'_>) -> Option<UnixTimestamp> {
+        let tai = match self.to_tai(table) {
+            Some(tai) => tai.0,
+            None => return Some(self.timestamp),
+        };
+        match table.smear_windows().find(|window| tai >= window.tai_start && tai < window.tai_start + window.tai_length) {
+            Some(window) => UnixTimestamp::checked_from_unix_timestamp(window.start + (tai - window.tai_start) * SMEAR_SECONDS / window.tai_length),
+            None => Some(self.timestamp),
+        }
+    }
+
+    // The instant a smeared clock reads `timestamp`, rounded up to the second
+    #[allow(clippy::manual_div_ceil)] // div_ceil is MSRV 1.73
+    pub fn from_smeared(timestamp: UnixTimestamp, table: &LeapSecondTable<'_>) -> Option<Self> {
+        let seconds = timestamp.unix_timestamp();
+        match table.smear_windows().find(|window| seconds >= window.start && seconds < window.start + SMEAR_SECONDS) {
+            Some(window) => {
+                let elapsed = ((seconds - window.start) * window.tai_length + SMEAR_SECONDS - 1) / SMEAR_SECONDS;
+                TaiTimestamp(window.tai_start + elapsed).to_utc(table)
+            },
+            None => Some(Self::new(timestamp)),
+        }
+    }
+}
+
+impl From<UnixTimestamp> for UtcTimestamp {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        Self::new(timestamp)
+    }
+}
+
+// Formatted as RFC 3339, e.g. 2016-12-31T23:59:60Z
+impl fmt::Display for UtcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.timestamp.to_year_month_day();
+        let seconds = self.timestamp.seconds_since_midnight();
+        let (hour, minute, second) = (seconds / 3_600, seconds / 60 % 60, seconds % 60 + self.is_leap_second as i64);
+        write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hour, minute, second)
+    }
+}
+
+// An owned table, parsed from the leap-seconds.list file published by the IERS and
+// shipped with tzdata. The SHA-1 hash line is not checked.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LeapSecondList {
+    leap_seconds: Vec<LeapSecond>,
+    expires: UnixTimestamp,
+}
+
+#[cfg(feature = "alloc")]
+impl LeapSecondList {
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        super::std_support::read_leap_second_list(path.as_ref())
+    }
+
+    pub fn table(&self) -> LeapSecondTable<'_> {
+        LeapSecondTable { leap_seconds: &self.leap_seconds, expires: self.expires }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for LeapSecondList {
+    type Err = ParseLeapSecondListError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ntp_timestamp = |field: Option<&str>| {
+            let seconds = field.ok_or(ParseLeapSecondListError)?.parse::<i64>().map_err(|_| ParseLeapSecondListError)?;
+            seconds.checked_sub(NTP_UNIX_OFFSET).and_then(UnixTimestamp::checked_from_unix_timestamp).ok_or(ParseLeapSecondListError)
+        };
+        let mut leap_seconds = Vec::new();
+        let mut expires = None;
+        for line in s.lines() {
+            if let Some(line) = line.strip_prefix("#@") {
+                expires = Some(ntp_timestamp(line.split_whitespace().next())?);
+                continue;
+            }
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            if let Some(timestamp) = fields.next() {
+                let timestamp = ntp_timestamp(Some(timestamp))?;
+                let tai_offset = fields.next().and_then(|field| field.parse().ok()).ok_or(ParseLeapSecondListError)?;
+                if fields.next().is_some() {
+                    return Err(ParseLeapSecondListError);
+                }
+                leap_seconds.push(LeapSecond::new(timestamp, tai_offset));
+            }
+        }
+        let expires = expires.ok_or(ParseLeapSecondListError)?;
+        LeapSecondTable::checked_new(&leap_seconds, expires).ok_or(ParseLeapSecondListError)?;
+        Ok(Self { leap_seconds, expires })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseLeapSecondListError;
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseLeapSecondListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid leap-seconds.list")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLeapSecondListError {}
@@ -1,9 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod business_day;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support;
+#[cfg(feature = "chrono")]
+mod chrono_support;
+mod clock;
+pub mod codec;
+#[cfg(any(feature = "proptest", feature = "quickcheck", feature = "arbitrary"))]
+mod generators;
+mod holiday;
+#[cfg(feature = "alloc")]
+mod interval_set;
+mod leap_seconds;
+#[cfg(all(feature = "libc", unix))]
+mod libc_support;
+mod local_result;
+mod offset;
+mod posix_tz;
+#[cfg(feature = "proptest")]
+mod proptest_support;
+#[cfg(feature = "prost")]
+mod prost_support;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_support;
 mod result;
+mod rfc3339;
+#[cfg(feature = "rkyv")]
+mod rkyv_support;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_support;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+mod sqlx_support;
+mod time_zone;
+#[cfg(feature = "time")]
+mod time_support;
+#[cfg(feature = "tzdb")]
+mod tzdb;
+#[cfg(feature = "alloc")]
+mod tzif;
 mod unix_timestamp;
 pub mod util;
 mod weekday;
+mod weekday_set;
 
 #[cfg(feature = "std")]
 mod std_support;
 
+#[cfg(feature = "arrow")]
+pub use self::arrow::ArrowTimestampError;
+pub use self::business_day::BusinessCalendar;
+pub use self::clock::{Clock, FixedClock, MockClock};
+#[cfg(all(feature = "libc", unix))]
+pub use self::clock::CoarseClock;
+#[cfg(feature = "std")]
+pub use self::clock::SystemClock;
+pub use self::codec::CodecError;
+pub use self::holiday::{HolidayCalendar, HolidayDate, HolidayRule, Holidays, Observance};
+#[cfg(feature = "alloc")]
+pub use self::interval_set::IntervalSet;
+pub use self::leap_seconds::{GpsTimestamp, LeapSecond, LeapSecondTable, TaiTimestamp, UtcTimestamp};
+#[cfg(feature = "alloc")]
+pub use self::leap_seconds::{LeapSecondList, ParseLeapSecondListError};
+pub use self::local_result::{Disambiguation, LocalResult, LocalTimeGap};
+pub use self::offset::{OffsetTimestamp, UtcOffset};
+pub use self::posix_tz::{ParsePosixTimeZoneError, PosixTimeZone, RuleDay};
+#[cfg(feature = "prost")]
+pub use self::prost_support::ProstTimestampError;
+pub use self::result::TimestampResult;
+#[cfg(feature = "rkyv")]
+pub use self::rkyv_support::ArchivedUnixTimestamp;
+pub use self::time_zone::LocalTimeType;
+#[cfg(feature = "alloc")]
+pub use self::time_zone::TimeZone;
+#[cfg(feature = "tzdb")]
+pub use self::tzdb::StaticTimeZone;
+#[cfg(feature = "alloc")]
+pub use self::tzif::TzifError;
 pub use self::unix_timestamp::UnixTimestamp;
@@ -0,0 +1,118 @@
+use core::{
+    convert::TryFrom,
+    mem::{self, MaybeUninit},
+};
+use super::{result::TimestampResult, util, UnixTimestamp};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const COARSE_CLOCK: libc::clockid_t = libc::CLOCK_REALTIME_COARSE;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const COARSE_CLOCK: libc::clockid_t = libc::CLOCK_REALTIME;
+
+// Reads CLOCK_REALTIME_COARSE where available, which is updated once per tick and
+// avoids the cost of reading the hardware clock
+pub fn clock_gettime_coarse() -> UnixTimestamp {
+    let mut timespec = MaybeUninit::<libc::timespec>::uninit();
+    // SAFETY: `timespec` is valid for writes and is initialized when the call succeeds
+    let timespec = unsafe {
+        if libc::clock_gettime(COARSE_CLOCK, timespec.as_mut_ptr()) != 0 {
+            panic!("clock_gettime failed");
+        }
+        timespec.assume_init()
+    };
+    UnixTimestamp::from_unix_timestamp(timespec.tv_sec as i64).expect("clock_gettime out of range of Timestamp")
+}
+
+// Days from 1/1/1970 in the proleptic Gregorian calendar for any year, unlike the
+// `UnixTimestamp` calendar functions that are limited to the years it supports
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// `time_t` is 32 bits on some targets, where it ends in 2038
+#[allow(clippy::unnecessary_fallible_conversions)]
+fn time_t(timestamp: UnixTimestamp) -> Result<libc::time_t, TimestampResult> {
+    let seconds = timestamp.unix_timestamp();
+    libc::time_t::try_from(seconds).ok().ok_or(TimestampResult::OverflowErr(seconds))
+}
+
+// The structs below are zeroed first because some targets add padding fields to them
+
+impl TryFrom<UnixTimestamp> for libc::timespec {
+    type Error = TimestampResult;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        // SAFETY: timespec only has integer fields
+        let mut timespec: libc::timespec = unsafe { mem::zeroed() };
+        timespec.tv_sec = time_t(timestamp)?;
+        Ok(timespec)
+    }
+}
+
+impl TryFrom<UnixTimestamp> for libc::timeval {
+    type Error = TimestampResult;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        // SAFETY: timeval only has integer fields
+        let mut timeval: libc::timeval = unsafe { mem::zeroed() };
+        timeval.tv_sec = time_t(timestamp)?;
+        Ok(timeval)
+    }
+}
+
+impl TryFrom<libc::timespec> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    #[allow(clippy::unnecessary_cast)] // time_t and c_long are 32 bits on some targets
+    fn try_from(timespec: libc::timespec) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp((timespec.tv_sec as i64).saturating_add((timespec.tv_nsec as i64).div_euclid(1_000_000_000))).into_result()
+    }
+}
+
+impl TryFrom<libc::timeval> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    #[allow(clippy::unnecessary_cast)] // time_t and suseconds_t are 32 bits on some targets
+    fn try_from(timeval: libc::timeval) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp((timeval.tv_sec as i64).saturating_add((timeval.tv_usec as i64).div_euclid(1_000_000))).into_result()
+    }
+}
+
+// Broken down in UTC like gmtime. Fields beyond the standard ones, such as tm_gmtoff
+// and tm_zone, are zero.
+impl From<UnixTimestamp> for libc::tm {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        let (year, month, day) = timestamp.to_year_month_day();
+        let (_, ordinal) = timestamp.to_year_ordinal();
+        let seconds = timestamp.seconds_since_midnight() as libc::c_int;
+        // SAFETY: tm only has integer fields and, on some targets, a pointer, which can all be zero
+        let mut tm: libc::tm = unsafe { mem::zeroed() };
+        tm.tm_sec = seconds % 60;
+        tm.tm_min = seconds / 60 % 60;
+        tm.tm_hour = seconds / 3_600;
+        tm.tm_mday = day as libc::c_int;
+        tm.tm_mon = month as libc::c_int - 1;
+        tm.tm_year = year as libc::c_int - 1900;
+        tm.tm_wday = timestamp.weekday().number_days_from_sunday() as libc::c_int;
+        tm.tm_yday = ordinal as libc::c_int - 1;
+        tm
+    }
+}
+
+// Read as UTC like timegm, so out of range fields carry over, e.g. tm_mday 32 of
+// January is February 1st. tm_wday, tm_yday and tm_isdst are ignored.
+impl TryFrom<libc::tm> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(tm: libc::tm) -> Result<Self, Self::Error> {
+        let month = tm.tm_mon as i64;
+        let year = tm.tm_year as i64 + 1900 + month.div_euclid(12);
+        let days = days_from_civil(year, month.rem_euclid(12) + 1, 1) + tm.tm_mday as i64 - 1;
+        UnixTimestamp::from_unix_timestamp(days * util::SECONDS_PER_DAY + tm.tm_hour as i64 * 3_600 + tm.tm_min as i64 * 60 + tm.tm_sec as i64).into_result()
+    }
+}
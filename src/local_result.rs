@@ -0,0 +1,154 @@
+use super::{unix_timestamp, util, UnixTimestamp, UtcOffset};
+
+// How to pick an instant for a local time that is repeated or skipped by a transition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    Earliest,
+    Latest,
+    // Skipped local times move forward by the length of the gap, repeated ones resolve to the earlier instant
+    ShiftForward,
+    Reject,
+}
+
+// A local time skipped by a forward transition, e.g. 02:30 when clocks jump from 02:00 to 03:00
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocalTimeGap {
+    local_timestamp: i64,
+    transition: UnixTimestamp,
+    offset_before: UtcOffset,
+    offset_after: UtcOffset,
+}
+
+impl LocalTimeGap {
+    pub const fn local_timestamp(self) -> i64 {
+        self.local_timestamp
+    }
+
+    // The first instant after the gap
+    pub const fn transition(self) -> UnixTimestamp {
+        self.transition
+    }
+
+    pub const fn offset_before(self) -> UtcOffset {
+        self.offset_before
+    }
+
+    pub const fn offset_after(self) -> UtcOffset {
+        self.offset_after
+    }
+
+    pub const fn length(self) -> i64 {
+        self.offset_after.seconds() as i64 - self.offset_before.seconds() as i64
+    }
+
+    // The local time read with the offset from before the transition
+    pub const fn shift_forward(self) -> Option<UnixTimestamp> {
+        UnixTimestamp::checked_from_unix_timestamp(self.local_timestamp.wrapping_sub(self.offset_before.seconds() as i64))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LocalResult {
+    Single(UnixTimestamp),
+    // Repeated by a backward transition, as (earlier, later)
+    Ambiguous(UnixTimestamp, UnixTimestamp),
+    None(LocalTimeGap),
+}
+
+impl LocalResult {
+    pub const fn single(self) -> Option<UnixTimestamp> {
+        match self {
+            Self::Single(timestamp) => Some(timestamp),
+            _ => None,
+        }
+    }
+
+    pub const fn earliest(self) -> Option<UnixTimestamp> {
+        match self {
+            Self::Single(timestamp) | Self::Ambiguous(timestamp, _) => Some(timestamp),
+            Self::None(_) => None,
+        }
+    }
+
+    pub const fn latest(self) -> Option<UnixTimestamp> {
+        match self {
+            Self::Single(timestamp) | Self::Ambiguous(_, timestamp) => Some(timestamp),
+            Self::None(_) => None,
+        }
+    }
+
+    pub const fn shift_forward(self) -> Option<UnixTimestamp> {
+        match self {
+            Self::None(gap) => gap.shift_forward(),
+            _ => self.earliest(),
+        }
+    }
+
+    pub const fn resolve(self, disambiguation: Disambiguation) -> Option<UnixTimestamp> {
+        match disambiguation {
+            Disambiguation::Earliest => self.earliest(),
+            Disambiguation::Latest => self.latest(),
+            Disambiguation::ShiftForward => self.shift_forward(),
+            Disambiguation::Reject => self.single(),
+        }
+    }
+}
+
+pub(super) const fn local_date_time_to_local_timestamp(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<i64> {
+    if !util::is_valid_year_month_day(year, month, day) || hour >= 24 || minute >= 60 || second >= 60 {
+        return None;
+    }
+    let days = unix_timestamp::year_month_day_to_julian_day_number(year, month, day) - util::UNIX_EPOCH_JULIAN_DAY_NUMBER;
+    Some(days as i64 * util::SECONDS_PER_DAY + hour as i64 * 3_600 + minute as i64 * 60 + second as i64)
+}
+
+// Shared by zones that can list every offset they use and look up the offset of any instant.
+// Transitions are assumed to be further apart than the spread of those offsets.
+pub(super) fn from_local_timestamp(
+    local_timestamp: i64,
+    offsets: impl Iterator<Item = UtcOffset> + Clone,
+    offset_at: impl Fn(UnixTimestamp) -> UtcOffset,
+) -> Option<LocalResult> {
+    let valid = offsets.clone().filter_map(|offset| {
+        let timestamp = UnixTimestamp::checked_from_unix_timestamp(local_timestamp.wrapping_sub(offset.seconds() as i64))?;
+        if offset_at(timestamp) == offset {
+            Some(timestamp)
+        } else {
+            None
+        }
+    });
+    let earliest = valid.clone().min();
+    match (earliest, valid.max()) {
+        (Some(earliest), Some(latest)) if earliest == latest => return Some(LocalResult::Single(earliest)),
+        (Some(earliest), Some(latest)) => return Some(LocalResult::Ambiguous(earliest, latest)),
+        _ => (),
+    }
+
+    // In a gap, `local_timestamp` is ahead of the local time of every instant before
+    // the transition and behind the local time of every instant from it on
+    let local_timestamp_at = |timestamp: i64| {
+        let timestamp = UnixTimestamp::checked_from_unix_timestamp(timestamp)?;
+        Some(timestamp.unix_timestamp() + offset_at(timestamp).seconds() as i64)
+    };
+    let mut low = local_timestamp.checked_sub(offsets.clone().max()?.seconds() as i64)?;
+    let mut high = local_timestamp.checked_sub(offsets.min()?.seconds() as i64)?;
+    if local_timestamp_at(low)? >= local_timestamp || local_timestamp_at(high)? <= local_timestamp {
+        return None;
+    }
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if local_timestamp_at(middle)? < local_timestamp {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let before = UnixTimestamp::checked_from_unix_timestamp(low)?;
+    let transition = UnixTimestamp::checked_from_unix_timestamp(high)?;
+    Some(LocalResult::None(LocalTimeGap {
+        local_timestamp,
+        transition,
+        offset_before: offset_at(before),
+        offset_after: offset_at(transition),
+    }))
+}
@@ -0,0 +1,202 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+use super::{
+    unix_timestamp,
+    util::{self, Weekday},
+    UnixTimestamp,
+};
+
+const MAX_OFFSET_SECONDS: i32 = 25 * 3_600 + 59 * 60 + 59;
+
+// Seconds east of UTC
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset(i32);
+
+impl UtcOffset {
+    pub const UTC: UtcOffset = Self::new(0);
+    pub const MIN: UtcOffset = Self::new(-MAX_OFFSET_SECONDS);
+    pub const MAX: UtcOffset = Self::new(MAX_OFFSET_SECONDS);
+
+    const fn new(seconds: i32) -> Self {
+        Self(seconds)
+    }
+
+    pub const fn checked_from_seconds(seconds: i32) -> Option<Self> {
+        if -MAX_OFFSET_SECONDS <= seconds && seconds <= MAX_OFFSET_SECONDS {
+            Some(Self::new(seconds))
+        } else {
+            None
+        }
+    }
+
+    // All components must share the same sign, e.g. -05:30 is (-5, -30, 0)
+    pub const fn checked_from_hms(hours: i8, minutes: i8, seconds: i8) -> Option<Self> {
+        let is_negative = hours < 0 || minutes < 0 || seconds < 0;
+        let is_positive = hours > 0 || minutes > 0 || seconds > 0;
+        if is_negative && is_positive || minutes <= -60 || minutes >= 60 || seconds <= -60 || seconds >= 60 {
+            return None;
+        }
+        Self::checked_from_seconds(hours as i32 * 3_600 + minutes as i32 * 60 + seconds as i32)
+    }
+
+    pub const fn seconds(self) -> i32 {
+        self.0
+    }
+
+    pub const fn to_hms(self) -> (i8, i8, i8) {
+        ((self.0 / 3_600) as i8, (self.0 / 60 % 60) as i8, (self.0 % 60) as i8)
+    }
+
+    pub const fn is_utc(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+}
+
+// Formatted as ±HH:MM, with seconds appended only when present
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { '-' } else { '+' };
+        let (hours, minutes, seconds) = self.to_hms();
+        write!(f, "{}{:02}:{:02}", sign, hours.abs(), minutes.abs())?;
+        if seconds != 0 {
+            write!(f, ":{:02}", seconds.abs())?;
+        }
+        Ok(())
+    }
+}
+
+// A UTC instant viewed from a fixed offset, so equality, ordering and hashing
+// only consider the instant
+#[derive(Clone, Copy, Debug)]
+pub struct OffsetTimestamp {
+    timestamp: UnixTimestamp,
+    offset: UtcOffset,
+}
+
+impl OffsetTimestamp {
+    pub const fn new(timestamp: UnixTimestamp, offset: UtcOffset) -> Self {
+        Self { timestamp, offset }
+    }
+
+    pub const fn checked_from_year_month_day(year: u16, month: u8, day: u8, offset: UtcOffset) -> Option<Self> {
+        if !util::is_valid_year_month_day(year, month, day) {
+            return None;
+        }
+        let days = unix_timestamp::year_month_day_to_julian_day_number(year, month, day) - util::UNIX_EPOCH_JULIAN_DAY_NUMBER;
+        Self::checked_from_local_timestamp(days as i64 * util::SECONDS_PER_DAY, offset)
+    }
+
+    // `local_timestamp` counts wall-clock seconds since 1/1/1970 @ 12:00:00AM in `offset`
+    pub const fn checked_from_local_timestamp(local_timestamp: i64, offset: UtcOffset) -> Option<Self> {
+        match UnixTimestamp::checked_from_unix_timestamp(local_timestamp.wrapping_sub(offset.seconds() as i64)) {
+            Some(timestamp) => Some(Self::new(timestamp, offset)),
+            None => None,
+        }
+    }
+
+    pub const fn timestamp(self) -> UnixTimestamp {
+        self.timestamp
+    }
+
+    pub const fn offset(self) -> UtcOffset {
+        self.offset
+    }
+
+    pub const fn to_offset(self, offset: UtcOffset) -> Self {
+        Self::new(self.timestamp, offset)
+    }
+
+    pub const fn local_timestamp(self) -> i64 {
+        self.timestamp.unix_timestamp() + self.offset.seconds() as i64
+    }
+
+    const fn local_days(self) -> i64 {
+        self.local_timestamp().div_euclid(util::SECONDS_PER_DAY) // MSRV 1.52
+    }
+
+    pub const fn checked_add(self, seconds: i64) -> Option<Self> {
+        match self.timestamp.checked_add(seconds) {
+            Some(timestamp) => Some(Self::new(timestamp, self.offset)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, seconds: i64) -> Option<Self> {
+        match self.timestamp.checked_sub(seconds) {
+            Some(timestamp) => Some(Self::new(timestamp, self.offset)),
+            None => None,
+        }
+    }
+
+    // Local midnight can be before `UnixTimestamp::MIN` when the offset is positive
+    pub const fn midnight(self) -> Option<Self> {
+        self.checked_sub(self.seconds_since_midnight())
+    }
+
+    pub const fn seconds_since_midnight(self) -> i64 {
+        self.local_timestamp().rem_euclid(util::SECONDS_PER_DAY)
+    }
+
+    pub const fn julian_day_number(self) -> i32 {
+        self.local_days() as i32 + util::UNIX_EPOCH_JULIAN_DAY_NUMBER
+    }
+
+    pub const fn to_year_month_day(self) -> (u16, u8, u8) {
+        unix_timestamp::julian_day_number_to_year_month_day(self.julian_day_number())
+    }
+
+    pub const fn to_year_ordinal(self) -> (u16, u16) {
+        let (year, month, day) = self.to_year_month_day();
+        (year, unix_timestamp::year_month_day_to_ordinal(year, month, day))
+    }
+
+    pub const fn weekday(self) -> Weekday {
+        // The unix epoch was a Thursday
+        Weekday::THURSDAY.nth_next(self.local_days().rem_euclid(7) as u64)
+    }
+}
+
+impl From<UnixTimestamp> for OffsetTimestamp {
+    fn from(timestamp: UnixTimestamp) -> Self {
+        Self::new(timestamp, UtcOffset::UTC)
+    }
+}
+
+impl From<OffsetTimestamp> for UnixTimestamp {
+    fn from(timestamp: OffsetTimestamp) -> Self {
+        timestamp.timestamp()
+    }
+}
+
+impl PartialEq for OffsetTimestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+    }
+}
+
+impl Eq for OffsetTimestamp {}
+
+impl PartialOrd for OffsetTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OffsetTimestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
+}
+
+impl Hash for OffsetTimestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp.hash(state);
+    }
+}
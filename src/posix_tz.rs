@@ -0,0 +1,314 @@
+use core::{fmt, str::FromStr};
+use super::{
+    local_result::{self, LocalResult},
+    time_zone::LocalTimeType,
+    unix_timestamp,
+    util::{self, Weekday},
+    OffsetTimestamp,
+    UnixTimestamp,
+    UtcOffset,
+};
+
+const DEFAULT_TRANSITION_TIME: i32 = 2 * 3_600;
+const MAX_TRANSITION_HOURS: i32 = 167;
+
+// Day of the year on which a daylight saving time transition happens
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RuleDay {
+    // Jn: 1 to 365, February 29th is never counted
+    Julian1(u16),
+    // n: 0 to 365, February 29th is counted in leap years
+    Julian0(u16),
+    // Mm.w.d: week 5 is the last week of the month
+    MonthWeekDay { month: u8, week: u8, weekday: Weekday },
+}
+
+impl RuleDay {
+    // Days since the unix epoch, which may be negative for 1969
+    const fn days_since_epoch(self, year: u16) -> i64 {
+        let january_1 = days_since_epoch(year, 1, 1);
+        match self {
+            Self::Julian1(day) => {
+                let leap_day = (util::is_leap_year(year) && day >= 60) as i64;
+                january_1 + day as i64 - 1 + leap_day
+            },
+            // Day 365 of a common year is clamped to December 31st, as glibc does
+            Self::Julian0(day) => {
+                let last_day = util::days_in_year(year) - 1;
+                january_1 + if day > last_day { last_day } else { day } as i64
+            },
+            Self::MonthWeekDay { month, week, weekday } => {
+                let first = days_since_epoch(year, month, 1);
+                let mut day = first + weekday_of(first).days_until(weekday) as i64 + 7 * (week as i64 - 1);
+                let last = first + util::days_in_year_month(year, month) as i64 - 1;
+                if day > last {
+                    day -= 7;
+                }
+                day
+            },
+        }
+    }
+}
+
+const fn days_since_epoch(year: u16, month: u8, day: u8) -> i64 {
+    (unix_timestamp::year_month_day_to_julian_day_number(year, month, day) - util::UNIX_EPOCH_JULIAN_DAY_NUMBER) as i64
+}
+
+const fn weekday_of(days_since_epoch: i64) -> Weekday {
+    // The unix epoch was a Thursday
+    Weekday::THURSDAY.nth_next(days_since_epoch.rem_euclid(7) as u64)
+}
+
+const fn year_of(local_timestamp: i64) -> u16 {
+    let days = local_timestamp.div_euclid(util::SECONDS_PER_DAY);
+    unix_timestamp::julian_day_number_to_year_month_day(days as i32 + util::UNIX_EPOCH_JULIAN_DAY_NUMBER).0
+}
+
+// Transition times are local wall-clock times, so `time` is seconds since midnight
+// and may extend past 24:00 or before 00:00 as allowed by RFC 8536
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) struct DaylightSavingTime {
+    pub(super) local_time_type: LocalTimeType,
+    pub(super) start: RuleDay,
+    pub(super) start_time: i32,
+    pub(super) end: RuleDay,
+    pub(super) end_time: i32,
+}
+
+// [POSIX TZ](https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html)
+// strings like "EST5EDT,M3.2.0,M11.1.0", as used in the footer of TZif files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PosixTimeZone {
+    standard: LocalTimeType,
+    daylight_saving: Option<DaylightSavingTime>,
+}
+
+impl PosixTimeZone {
+    // Transition times must be within 167 hours of midnight
+    #[cfg(feature = "tzdb")]
+    pub(super) const fn new(standard: LocalTimeType, daylight_saving: Option<DaylightSavingTime>) -> Self {
+        Self { standard, daylight_saving }
+    }
+
+    pub const fn fixed(standard: LocalTimeType) -> Self {
+        Self { standard, daylight_saving: None }
+    }
+
+    pub const fn standard(&self) -> &LocalTimeType {
+        &self.standard
+    }
+
+    pub const fn daylight_saving(&self) -> Option<&LocalTimeType> {
+        match &self.daylight_saving {
+            Some(dst) => Some(&dst.local_time_type),
+            None => None,
+        }
+    }
+
+    // Transitions of `year` in UTC, as (start of daylight saving time, end of daylight saving time)
+    pub fn transitions(&self, year: u16) -> Option<(i64, i64)> {
+        let dst = self.daylight_saving.as_ref()?;
+        let start = dst.start.days_since_epoch(year) * util::SECONDS_PER_DAY + dst.start_time as i64 - self.standard.offset().seconds() as i64;
+        let end = dst.end.days_since_epoch(year) * util::SECONDS_PER_DAY + dst.end_time as i64 - dst.local_time_type.offset().seconds() as i64;
+        Some((start, end))
+    }
+
+    pub fn local_time_type(&self, timestamp: UnixTimestamp) -> &LocalTimeType {
+        let timestamp = timestamp.unix_timestamp();
+        let dst = match &self.daylight_saving {
+            Some(dst) => dst,
+            None => return &self.standard,
+        };
+        let year = year_of(timestamp + self.standard.offset().seconds() as i64);
+        let (start, end) = match self.transitions(year) {
+            Some(transitions) => transitions,
+            None => return &self.standard,
+        };
+        // Daylight saving time spans the new year in the southern hemisphere
+        let is_dst = if start <= end {
+            start <= timestamp && timestamp < end
+        } else {
+            timestamp < end || start <= timestamp
+        };
+        if is_dst {
+            &dst.local_time_type
+        } else {
+            &self.standard
+        }
+    }
+
+    pub fn offset(&self, timestamp: UnixTimestamp) -> UtcOffset {
+        self.local_time_type(timestamp).offset()
+    }
+
+    pub fn to_offset_timestamp(&self, timestamp: UnixTimestamp) -> OffsetTimestamp {
+        OffsetTimestamp::new(timestamp, self.offset(timestamp))
+    }
+
+    pub(super) fn local_time_types(&self) -> impl Iterator<Item = &LocalTimeType> + Clone {
+        Some(&self.standard).into_iter().chain(self.daylight_saving())
+    }
+
+    pub fn checked_from_local_timestamp(&self, local_timestamp: i64) -> Option<LocalResult> {
+        local_result::from_local_timestamp(local_timestamp, self.local_time_types().map(LocalTimeType::offset), |timestamp| self.offset(timestamp))
+    }
+
+    pub fn checked_from_local_date_time(&self, year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<LocalResult> {
+        self.checked_from_local_timestamp(local_result::local_date_time_to_local_timestamp(year, month, day, hour, minute, second)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParsePosixTimeZoneError;
+
+impl fmt::Display for ParsePosixTimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid POSIX TZ string")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePosixTimeZoneError {}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.first().copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.bytes = &self.bytes[1..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a [u8] {
+        let len = self.bytes.iter().position(|&byte| !predicate(byte)).unwrap_or(self.bytes.len());
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        taken
+    }
+
+    // Either 3 or more letters, or 3 or more alphanumerics, '+' or '-' quoted by '<' and '>'
+    fn name(&mut self) -> Result<&'a [u8], ParsePosixTimeZoneError> {
+        let name = if self.eat(b'<') {
+            let name = self.take_while(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-');
+            if !self.eat(b'>') {
+                return Err(ParsePosixTimeZoneError);
+            }
+            name
+        } else {
+            self.take_while(|byte| byte.is_ascii_alphabetic())
+        };
+        if name.len() < 3 {
+            return Err(ParsePosixTimeZoneError);
+        }
+        Ok(name)
+    }
+
+    fn number(&mut self, max: i32) -> Result<i32, ParsePosixTimeZoneError> {
+        let digits = self.take_while(|byte| byte.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return Err(ParsePosixTimeZoneError);
+        }
+        let number = digits.iter().fold(0, |number, &digit| number * 10 + (digit - b'0') as i32);
+        if number > max {
+            return Err(ParsePosixTimeZoneError);
+        }
+        Ok(number)
+    }
+
+    // [+-]hh[:mm[:ss]]
+    fn time(&mut self, max_hours: i32) -> Result<i32, ParsePosixTimeZoneError> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number(max_hours)? * 3_600;
+        if self.eat(b':') {
+            seconds += self.number(59)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(59)?;
+            }
+        }
+        Ok(sign * seconds)
+    }
+
+    // POSIX offsets are positive west of Greenwich, the opposite of `UtcOffset`
+    fn offset(&mut self) -> Result<UtcOffset, ParsePosixTimeZoneError> {
+        UtcOffset::checked_from_seconds(-self.time(24)?).ok_or(ParsePosixTimeZoneError)
+    }
+
+    fn rule_day(&mut self) -> Result<RuleDay, ParsePosixTimeZoneError> {
+        if self.eat(b'J') {
+            match self.number(365)? {
+                0 => Err(ParsePosixTimeZoneError),
+                day => Ok(RuleDay::Julian1(day as u16)),
+            }
+        } else if self.eat(b'M') {
+            let month = self.number(12)?;
+            if month == 0 || !self.eat(b'.') {
+                return Err(ParsePosixTimeZoneError);
+            }
+            let week = self.number(5)?;
+            if week == 0 || !self.eat(b'.') {
+                return Err(ParsePosixTimeZoneError);
+            }
+            let weekday = Weekday::SUNDAY.nth_next(self.number(6)? as u64);
+            Ok(RuleDay::MonthWeekDay { month: month as u8, week: week as u8, weekday })
+        } else {
+            Ok(RuleDay::Julian0(self.number(365)? as u16))
+        }
+    }
+
+    fn rule(&mut self) -> Result<(RuleDay, i32), ParsePosixTimeZoneError> {
+        let day = self.rule_day()?;
+        let time = if self.eat(b'/') { self.time(MAX_TRANSITION_HOURS)? } else { DEFAULT_TRANSITION_TIME };
+        Ok((day, time))
+    }
+}
+
+impl FromStr for PosixTimeZone {
+    type Err = ParsePosixTimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { bytes: s.as_bytes() };
+        let standard_name = parser.name()?;
+        let standard_offset = parser.offset()?;
+        let standard = LocalTimeType::checked_new(standard_offset, false, standard_name).ok_or(ParsePosixTimeZoneError)?;
+        if parser.bytes.is_empty() {
+            return Ok(Self::fixed(standard));
+        }
+
+        let dst_name = parser.name()?;
+        let dst_offset = match parser.peek() {
+            Some(b',') | None => UtcOffset::checked_from_seconds(standard_offset.seconds() + 3_600).ok_or(ParsePosixTimeZoneError)?,
+            Some(_) => parser.offset()?,
+        };
+        let local_time_type = LocalTimeType::checked_new(dst_offset, true, dst_name).ok_or(ParsePosixTimeZoneError)?;
+        // Without rules, fall back to the United States rules like most implementations
+        let ((start, start_time), (end, end_time)) = if parser.eat(b',') {
+            let start = parser.rule()?;
+            if !parser.eat(b',') {
+                return Err(ParsePosixTimeZoneError);
+            }
+            (start, parser.rule()?)
+        } else {
+            let start = RuleDay::MonthWeekDay { month: 3, week: 2, weekday: Weekday::SUNDAY };
+            let end = RuleDay::MonthWeekDay { month: 11, week: 1, weekday: Weekday::SUNDAY };
+            ((start, DEFAULT_TRANSITION_TIME), (end, DEFAULT_TRANSITION_TIME))
+        };
+        if !parser.bytes.is_empty() {
+            return Err(ParsePosixTimeZoneError);
+        }
+        Ok(Self { standard, daylight_saving: Some(DaylightSavingTime { local_time_type, start, start_time, end, end_time }) })
+    }
+}
@@ -0,0 +1,32 @@
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    sample,
+    strategy::{BoxedStrategy, Strategy},
+};
+use super::{generators, util::Weekday, UnixTimestamp};
+
+// Any timestamp in range, with edge cases such as leap days, new years and `MAX` more
+// likely. Shrinks toward 1/1/1970.
+impl Arbitrary for UnixTimestamp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            3 => (0..=UnixTimestamp::MAX.unix_timestamp() as u64).prop_map(generators::uniform),
+            1 => (any::<u8>(), any::<u64>()).prop_map(|(kind, value)| generators::edge_case(kind, value)),
+        ]
+        .boxed()
+    }
+}
+
+// Shrinks toward Monday
+impl Arbitrary for Weekday {
+    type Parameters = ();
+    type Strategy = sample::Select<Weekday>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        sample::select(&Weekday::ALL[..])
+    }
+}
@@ -0,0 +1,57 @@
+use core::{convert::TryFrom, fmt};
+use super::UnixTimestamp;
+
+// The range documented for google.protobuf.Timestamp, 1/1/0001 through 12/31/9999
+const PROTO_MIN_SECONDS: i64 = -62_135_596_800;
+const PROTO_MAX_SECONDS: i64 = 253_402_300_799;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProstTimestampError {
+    // nanos must be from 0 to 999,999,999, counting forward from seconds
+    InvalidNanos(i32),
+    // Seconds outside of the range documented for google.protobuf.Timestamp
+    OutOfProtoRange(i64),
+    // A valid google.protobuf.Timestamp before 1/1/1970
+    OutOfRange(i64),
+}
+
+impl fmt::Display for ProstTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNanos(nanos) => write!(f, "timestamp nanos {} are not from 0 to 999999999", nanos),
+            Self::OutOfProtoRange(seconds) => write!(f, "timestamp seconds {} are not from 0001-01-01 to 9999-12-31", seconds),
+            Self::OutOfRange(seconds) => write!(f, "timestamp seconds {} are out of range of UnixTimestamp", seconds),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProstTimestampError {}
+
+// Only `UnixTimestamp::MAX`, 1/1/10000, is out of the documented range
+impl TryFrom<UnixTimestamp> for prost_types::Timestamp {
+    type Error = ProstTimestampError;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        let seconds = timestamp.unix_timestamp();
+        if seconds > PROTO_MAX_SECONDS {
+            return Err(ProstTimestampError::OutOfProtoRange(seconds));
+        }
+        Ok(prost_types::Timestamp { seconds, nanos: 0 })
+    }
+}
+
+// Nanos are truncated
+impl TryFrom<prost_types::Timestamp> for UnixTimestamp {
+    type Error = ProstTimestampError;
+
+    fn try_from(timestamp: prost_types::Timestamp) -> Result<Self, Self::Error> {
+        if !(0..1_000_000_000).contains(&timestamp.nanos) {
+            return Err(ProstTimestampError::InvalidNanos(timestamp.nanos));
+        }
+        if !(PROTO_MIN_SECONDS..=PROTO_MAX_SECONDS).contains(&timestamp.seconds) {
+            return Err(ProstTimestampError::OutOfProtoRange(timestamp.seconds));
+        }
+        UnixTimestamp::checked_from_unix_timestamp(timestamp.seconds).ok_or(ProstTimestampError::OutOfRange(timestamp.seconds))
+    }
+}
@@ -0,0 +1,25 @@
+use quickcheck::{Arbitrary, Gen};
+use super::{generators, util::Weekday, UnixTimestamp};
+
+// Any timestamp in range, with edge cases such as leap days, new years and `MAX` more
+// likely. Shrinks toward 1/1/1970.
+impl Arbitrary for UnixTimestamp {
+    fn arbitrary(g: &mut Gen) -> Self {
+        generators::timestamp(u8::arbitrary(g), u64::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.unix_timestamp().shrink().filter_map(UnixTimestamp::checked_from_unix_timestamp))
+    }
+}
+
+// Shrinks toward Monday
+impl Arbitrary for Weekday {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&Weekday::ALL).unwrap()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((0..self.number_days_from_monday()).map(|days| Weekday::ALL[days as usize]))
+    }
+}
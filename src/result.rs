@@ -11,7 +11,7 @@ impl TimestampResult {
     pub fn expect(self, msg: &'static str) -> UnixTimestamp {
         match self {
             Self::TimestampOk(timestamp) => timestamp,
-            _ => panic!(msg),
+            _ => panic!("{}", msg),
         }
     }
 
@@ -22,6 +22,16 @@ impl TimestampResult {
         }
     }
 
+    // For `TryFrom` conversions from other date and time types, which truncate subseconds.
+    // Out of range times give the overflowed seconds, which `unwrap` saturates.
+    #[cfg(any(feature = "chrono", feature = "time", all(feature = "libc", unix)))]
+    pub(super) fn into_result(self) -> Result<UnixTimestamp, Self> {
+        match self {
+            Self::TimestampOk(timestamp) => Ok(timestamp),
+            overflow => Err(overflow),
+        }
+    }
+
     pub const fn unwrap(self) -> UnixTimestamp {
         match self {
             Self::TimestampOk(timestamp) => timestamp,
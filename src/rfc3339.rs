@@ -0,0 +1,96 @@
+#[cfg(feature = "serde")]
+use core::fmt;
+use core::ops::Range;
+use super::{local_result, util, OffsetTimestamp, UtcOffset};
+
+// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) date and time strings, shared by
+// the serde, CBOR and SQL support
+
+// Formats as e.g. 2021-03-14T03:00:00-04:00, or with Z for UTC
+#[cfg(feature = "serde")]
+pub(super) struct Rfc3339(pub(super) OffsetTimestamp);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.0.to_year_month_day();
+        let seconds = self.0.seconds_since_midnight();
+        write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, seconds / 3_600, seconds / 60 % 60, seconds % 60)?;
+        if self.0.offset().is_utc() {
+            f.write_str("Z")
+        } else {
+            write!(f, "{}", self.0.offset())
+        }
+    }
+}
+
+fn digits(bytes: &[u8], range: Range<usize>) -> Option<u16> {
+    bytes.get(range)?.iter().try_fold(0u16, |n, &digit| if digit.is_ascii_digit() { Some(n * 10 + (digit - b'0') as u16) } else { None })
+}
+
+// Fractional seconds are truncated, 23:59:60 UTC is read as the following midnight like
+// POSIX does, and offsets may carry seconds as `UtcOffset` displays them
+pub(super) fn parse_rfc3339(bytes: &[u8]) -> Option<OffsetTimestamp> {
+    parse(bytes, false)
+}
+
+// Also reads what SQL databases return for dates and times, which may leave out the time,
+// the offset or its minutes, e.g. 2021-03-14 or 2021-03-14 07:00:00+00. A missing offset
+// is UTC.
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite", feature = "rusqlite"))]
+pub(super) fn parse_sql(bytes: &[u8]) -> Option<OffsetTimestamp> {
+    if bytes.len() == 10 {
+        let mut date_time = *b"0000-00-00 00:00:00";
+        date_time[..10].copy_from_slice(bytes);
+        return parse(&date_time, true);
+    }
+    parse(bytes, true)
+}
+
+fn parse(bytes: &[u8], sql: bool) -> Option<OffsetTimestamp> {
+    let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+    if bytes.len() < 19 || separators.iter().any(|&(i, separator)| bytes[i] != separator) || !matches!(bytes[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let second = digits(bytes, 17..19)?;
+    if second > 60 {
+        return None;
+    }
+    let local_timestamp = local_result::local_date_time_to_local_timestamp(
+        digits(bytes, 0..4)?,
+        digits(bytes, 5..7)? as u8,
+        digits(bytes, 8..10)? as u8,
+        digits(bytes, 11..13)? as u8,
+        digits(bytes, 14..16)? as u8,
+        second.min(59) as u8,
+    )?;
+
+    let mut rest = &bytes[19..];
+    if let Some((b'.', fraction)) = rest.split_first() {
+        let len = fraction.iter().take_while(|digit| digit.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        rest = &fraction[len..];
+    }
+    let offset = match rest.split_first() {
+        None if sql => UtcOffset::UTC,
+        Some((b'Z', [])) | Some((b'z', [])) => UtcOffset::UTC,
+        Some((&sign, offset)) if (sign == b'+' || sign == b'-') && (offset.len() == 5 || offset.len() == 8 || sql && offset.len() == 2) => {
+            if offset.len() > 2 && offset[2] != b':' || offset.len() == 8 && offset[5] != b':' {
+                return None;
+            }
+            let minutes = if offset.len() > 2 { digits(offset, 3..5)? } else { 0 };
+            let seconds = if offset.len() == 8 { digits(offset, 6..8)? } else { 0 };
+            let sign = if sign == b'-' { -1 } else { 1 };
+            UtcOffset::checked_from_hms(sign * digits(offset, 0..2)? as i8, sign * minutes as i8, sign * seconds as i8)?
+        },
+        _ => return None,
+    };
+    // A leap second can only be the last second of a UTC day
+    let is_leap_second = second == 60;
+    if is_leap_second && (local_timestamp - offset.seconds() as i64).rem_euclid(util::SECONDS_PER_DAY) != util::SECONDS_PER_DAY - 1 {
+        return None;
+    }
+    OffsetTimestamp::checked_from_local_timestamp(local_timestamp + is_leap_second as i64, offset)
+}
@@ -0,0 +1,98 @@
+use core::{error::Error, fmt};
+use rkyv::{
+    bytecheck::CheckBytes,
+    primitive::ArchivedI64,
+    rancor::{fail, Fallible, Source},
+    traits::NoUndef,
+    Archive,
+    Deserialize,
+    Place,
+    Portable,
+    Serialize,
+};
+use super::UnixTimestamp;
+
+// An archived `UnixTimestamp`, seconds as an `i64` in the byte order of the archive.
+// Validating an archive rejects seconds out of range of `UnixTimestamp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArchivedUnixTimestamp(ArchivedI64);
+
+// SAFETY: `ArchivedUnixTimestamp` is `repr(transparent)` over the portable `ArchivedI64`
+unsafe impl Portable for ArchivedUnixTimestamp {}
+
+// SAFETY: `ArchivedI64` has no padding
+unsafe impl NoUndef for ArchivedUnixTimestamp {}
+
+impl ArchivedUnixTimestamp {
+    pub fn unix_timestamp(&self) -> i64 {
+        self.0.to_native()
+    }
+
+    // Saturates like `TimestampResult::unwrap` if the archive was accessed without being
+    // validated and holds seconds out of range
+    pub fn to_native(&self) -> UnixTimestamp {
+        UnixTimestamp::from_unix_timestamp(self.unix_timestamp()).unwrap()
+    }
+}
+
+impl PartialEq<UnixTimestamp> for ArchivedUnixTimestamp {
+    fn eq(&self, other: &UnixTimestamp) -> bool {
+        self.unix_timestamp() == other.unix_timestamp()
+    }
+}
+
+impl PartialEq<ArchivedUnixTimestamp> for UnixTimestamp {
+    fn eq(&self, other: &ArchivedUnixTimestamp) -> bool {
+        other == self
+    }
+}
+
+#[derive(Debug)]
+struct OutOfRangeError(i64);
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "archived timestamp seconds {} are out of range of UnixTimestamp", self.0)
+    }
+}
+
+impl Error for OutOfRangeError {}
+
+// SAFETY: every bit pattern of `ArchivedI64` is valid, and the value is only accepted when
+// it is in range of `UnixTimestamp`
+unsafe impl<C> CheckBytes<C> for ArchivedUnixTimestamp
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees that `value` is aligned and points to enough bytes
+        let seconds = unsafe { (*value).unix_timestamp() };
+        if UnixTimestamp::checked_from_unix_timestamp(seconds).is_none() {
+            fail!(OutOfRangeError(seconds));
+        }
+        Ok(())
+    }
+}
+
+impl Archive for UnixTimestamp {
+    type Archived = ArchivedUnixTimestamp;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(ArchivedUnixTimestamp(ArchivedI64::from_native(self.unix_timestamp())));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for UnixTimestamp {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<UnixTimestamp, D> for ArchivedUnixTimestamp {
+    fn deserialize(&self, _: &mut D) -> Result<UnixTimestamp, D::Error> {
+        Ok(self.to_native())
+    }
+}
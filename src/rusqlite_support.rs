@@ -0,0 +1,23 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use super::UnixTimestamp;
+
+// Stored as INTEGER seconds
+impl ToSql for UnixTimestamp {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(self.unix_timestamp().into())
+    }
+}
+
+// Reads INTEGER seconds, or TEXT as SQLite's date and time functions write it, taking a
+// missing offset as UTC
+impl FromSql for UnixTimestamp {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(seconds) => UnixTimestamp::checked_from_unix_timestamp(seconds).ok_or(FromSqlError::OutOfRange(seconds)),
+            ValueRef::Text(text) => super::rfc3339::parse_sql(text)
+                .map(|timestamp| timestamp.timestamp())
+                .ok_or_else(|| FromSqlError::Other(format!("invalid timestamp {:?}", String::from_utf8_lossy(text)).into())),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
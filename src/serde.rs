@@ -0,0 +1,220 @@
+// Serde support. `UnixTimestamp`, `TaiTimestamp` and `GpsTimestamp` are integer seconds,
+// `UtcOffset` is integer seconds east of UTC, `Weekday` is its ISO 8601 number,
+// `WeekdaySet` is its bits and `OffsetTimestamp` is an RFC 3339 string.
+//
+// The modules here select other representations of `UnixTimestamp` for `#[serde(with)]`:
+//
+//     #[derive(Serialize, Deserialize)]
+//     struct Event {
+//         #[serde(with = "practicaltimestamp::serde::rfc3339")]
+//         created: UnixTimestamp,
+//         #[serde(default, with = "practicaltimestamp::serde::milliseconds::option")]
+//         deleted: Option<UnixTimestamp>,
+//     }
+
+use core::{convert::TryFrom, fmt};
+use ::serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use super::{
+    rfc3339::{parse_rfc3339, Rfc3339},
+    util::{Weekday, WeekdaySet},
+    GpsTimestamp,
+    OffsetTimestamp,
+    TaiTimestamp,
+    UnixTimestamp,
+    UtcOffset,
+};
+
+const TIMESTAMP_RANGE: &str = "a unix timestamp from 1970 through 9999";
+
+fn timestamp_from_seconds<E: de::Error>(seconds: i64) -> Result<UnixTimestamp, E> {
+    UnixTimestamp::checked_from_unix_timestamp(seconds).ok_or_else(|| E::invalid_value(Unexpected::Signed(seconds), &TIMESTAMP_RANGE))
+}
+
+impl Serialize for UnixTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.unix_timestamp())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnixTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        timestamp_from_seconds(i64::deserialize(deserializer)?)
+    }
+}
+
+impl Serialize for TaiTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.seconds())
+    }
+}
+
+impl<'de> Deserialize<'de> for TaiTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for GpsTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.seconds())
+    }
+}
+
+impl<'de> Deserialize<'de> for GpsTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for UtcOffset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.seconds())
+    }
+}
+
+impl<'de> Deserialize<'de> for UtcOffset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let seconds = i32::deserialize(deserializer)?;
+        Self::checked_from_seconds(seconds).ok_or_else(|| de::Error::invalid_value(Unexpected::Signed(seconds as i64), &"an offset of at most 25:59:59"))
+    }
+}
+
+impl Serialize for Weekday {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.number_from_monday())
+    }
+}
+
+impl<'de> Deserialize<'de> for Weekday {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let number = u8::deserialize(deserializer)?;
+        Self::try_from(number).map_err(|_| de::Error::invalid_value(Unexpected::Unsigned(number as u64), &"a weekday number between 1 and 7"))
+    }
+}
+
+impl Serialize for WeekdaySet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for WeekdaySet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u8::deserialize(deserializer)?;
+        Self::checked_from_bits(bits).ok_or_else(|| de::Error::invalid_value(Unexpected::Unsigned(bits as u64), &"weekday bits below 0x80"))
+    }
+}
+
+impl Serialize for OffsetTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Rfc3339(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for OffsetTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+}
+
+struct Rfc3339Visitor;
+
+impl<'de> Visitor<'de> for Rfc3339Visitor {
+    type Value = OffsetTimestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an RFC 3339 date and time from 1970 through 9999")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        parse_rfc3339(s.as_bytes()).ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self))
+    }
+}
+
+// `UnixTimestamp` as an RFC 3339 string in UTC, e.g. "2021-03-14T07:00:00Z". Any offset is
+// accepted when deserializing.
+pub mod rfc3339 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(timestamp: &UnixTimestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Rfc3339(OffsetTimestamp::from(*timestamp)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UnixTimestamp, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor).map(OffsetTimestamp::timestamp)
+    }
+
+    struct Wrapper(UnixTimestamp);
+
+    impl Serialize for Wrapper {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Wrapper {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(Self)
+        }
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(timestamp: &Option<UnixTimestamp>, serializer: S) -> Result<S::Ok, S::Error> {
+            timestamp.map(Wrapper).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<UnixTimestamp>, D::Error> {
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+        }
+    }
+}
+
+// `UnixTimestamp` as integer milliseconds, as used by JavaScript and Java. Milliseconds
+// are rounded down to the second when deserializing.
+pub mod milliseconds {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(timestamp: &UnixTimestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(timestamp.unix_timestamp() * 1_000)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UnixTimestamp, D::Error> {
+        let milliseconds = i64::deserialize(deserializer)?;
+        UnixTimestamp::checked_from_unix_timestamp(milliseconds.div_euclid(1_000))
+            .ok_or_else(|| de::Error::invalid_value(Unexpected::Signed(milliseconds), &TIMESTAMP_RANGE))
+    }
+
+    struct Wrapper(UnixTimestamp);
+
+    impl Serialize for Wrapper {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Wrapper {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(Self)
+        }
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(timestamp: &Option<UnixTimestamp>, serializer: S) -> Result<S::Ok, S::Error> {
+            timestamp.map(Wrapper).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<UnixTimestamp>, D::Error> {
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use sqlx::{encode::IsNull, error::BoxDynError};
+use super::UnixTimestamp;
+
+fn from_seconds(seconds: i64) -> Result<UnixTimestamp, BoxDynError> {
+    UnixTimestamp::checked_from_unix_timestamp(seconds).ok_or_else(|| format!("timestamp {} out of range of UnixTimestamp", seconds).into())
+}
+
+fn from_text(text: &str) -> Result<UnixTimestamp, BoxDynError> {
+    super::rfc3339::parse_sql(text.as_bytes()).map(|timestamp| timestamp.timestamp()).ok_or_else(|| format!("invalid timestamp {:?}", text).into())
+}
+
+// TIMESTAMPTZ, and TIMESTAMP read as UTC, are microseconds from 1/1/2000 in the binary
+// protocol, and DATE is days from 1/1/2000
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use core::{convert::TryFrom, str};
+    use sqlx::{
+        postgres::{types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+        Decode,
+        Encode,
+        Type,
+        ValueRef,
+    };
+    use super::{from_seconds, from_text, BoxDynError, IsNull};
+    use crate::{util, UnixTimestamp};
+
+    const POSTGRES_EPOCH: i64 = 946_684_800;
+
+    const DATE_OID: u32 = 1082;
+    const DATE: PgTypeInfo = PgTypeInfo::with_oid(Oid(DATE_OID));
+    const TIMESTAMP: PgTypeInfo = PgTypeInfo::with_oid(Oid(1114));
+    const TIMESTAMPTZ: PgTypeInfo = PgTypeInfo::with_oid(Oid(1184));
+    const TIMESTAMPTZ_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1185));
+
+    impl Type<Postgres> for UnixTimestamp {
+        fn type_info() -> PgTypeInfo {
+            TIMESTAMPTZ
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            *ty == TIMESTAMPTZ || *ty == TIMESTAMP || *ty == DATE
+        }
+    }
+
+    impl PgHasArrayType for UnixTimestamp {
+        fn array_type_info() -> PgTypeInfo {
+            TIMESTAMPTZ_ARRAY
+        }
+    }
+
+    impl Encode<'_, Postgres> for UnixTimestamp {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            let micros = (self.unix_timestamp() - POSTGRES_EPOCH) * 1_000_000;
+            Encode::<Postgres>::encode(micros, buf)
+        }
+
+        fn size_hint(&self) -> usize {
+            8
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for UnixTimestamp {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            let oid = value.type_info().oid().map_or(0, |oid| oid.0);
+            decode(value.as_bytes()?, oid, value.format())
+        }
+    }
+
+    // Decodes a non-null value of the type with `oid`, as sent by the server. Subseconds are
+    // truncated, and a DATE is midnight UTC.
+    fn decode(bytes: &[u8], oid: u32, format: PgValueFormat) -> Result<UnixTimestamp, BoxDynError> {
+        match format {
+            PgValueFormat::Binary if oid == DATE_OID => {
+                let days = i32::from_be_bytes(<[u8; 4]>::try_from(bytes)?);
+                from_seconds((i64::from(days) * util::SECONDS_PER_DAY).saturating_add(POSTGRES_EPOCH))
+            },
+            PgValueFormat::Binary => {
+                let micros = i64::from_be_bytes(<[u8; 8]>::try_from(bytes)?);
+                from_seconds(micros.div_euclid(1_000_000) + POSTGRES_EPOCH)
+            },
+            PgValueFormat::Text => from_text(str::from_utf8(bytes)?),
+        }
+    }
+
+    // Values as sent by the server, in the binary protocol unless they are text
+    #[cfg(test)]
+    mod tests {
+        use sqlx::postgres::PgValueFormat::{Binary, Text};
+        use super::decode;
+        use crate::UnixTimestamp;
+
+        const DATE: u32 = 1082;
+        const TIMESTAMP: u32 = 1114;
+        const TIMESTAMPTZ: u32 = 1184;
+
+        fn ts(timestamp: i64) -> UnixTimestamp {
+            UnixTimestamp::from_unix_timestamp(timestamp).unwrap()
+        }
+
+        #[test]
+        fn binary() {
+            const TEST_CASES: &[(&[u8], u32, Option<i64>)] = &[
+                (&[0; 8], TIMESTAMPTZ, Some(946_684_800)),
+                (&[0xff, 0xfc, 0xa2, 0xfe, 0xc4, 0xc8, 0x20, 0x00], TIMESTAMPTZ, Some(0)),
+                // Microseconds before 1/1/2000 are floored to the previous second
+                (&[0xff; 8], TIMESTAMPTZ, Some(946_684_799)),
+                (&[0x00, 0x02, 0x60, 0x78, 0x73, 0x51, 0xbd, 0x20], TIMESTAMPTZ, Some(1_615_705_200)),
+                (&[0x00, 0x02, 0x60, 0x78, 0x73, 0x51, 0xbd, 0x20], TIMESTAMP, Some(1_615_705_200)),
+                (&[0x03, 0x80, 0xe7, 0x0b, 0x91, 0x3b, 0x80, 0x00], TIMESTAMPTZ, Some(253_402_300_800)),
+                // infinity and -infinity
+                (&[0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff], TIMESTAMPTZ, None),
+                (&[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], TIMESTAMPTZ, None),
+                (&[0xff, 0xfc, 0xa2, 0xfe, 0xc4, 0xc8, 0x1f, 0xff], TIMESTAMPTZ, None),
+                (&[0; 4], TIMESTAMPTZ, None),
+                (&[0; 4], DATE, Some(946_684_800)),
+                (&[0xff, 0xff, 0xd5, 0x33], DATE, Some(0)),
+                (&[0x00, 0x00, 0x1e, 0x3f], DATE, Some(1_615_680_000)),
+                (&[0x7f, 0xff, 0xff, 0xff], DATE, None),
+                (&[0; 8], DATE, None),
+            ];
+
+            for &(bytes, oid, expected) in TEST_CASES {
+                assert_eq!(decode(bytes, oid, Binary).ok(), expected.map(ts), "{:x?} {}", bytes, oid);
+            }
+        }
+
+        #[test]
+        fn text() {
+            const TEST_CASES: &[(&str, u32, Option<i64>)] = &[
+                ("2021-03-14 07:00:00+00", TIMESTAMPTZ, Some(1_615_705_200)),
+                ("2021-03-14 03:00:00.5-04", TIMESTAMPTZ, Some(1_615_705_200)),
+                ("2021-03-14 07:00:00", TIMESTAMP, Some(1_615_705_200)),
+                ("2021-03-14", DATE, Some(1_615_680_000)),
+                ("1969-12-31 23:59:59+00", TIMESTAMPTZ, None),
+                ("infinity", TIMESTAMPTZ, None),
+            ];
+
+            for &(text, oid, expected) in TEST_CASES {
+                assert_eq!(decode(text.as_bytes(), oid, Text).ok(), expected.map(ts), "{} {}", text, oid);
+            }
+            assert!(decode(b"\xff", TIMESTAMPTZ, Text).is_err());
+        }
+    }
+}
+
+// INTEGER seconds, or TEXT as SQLite's date and time functions write it
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use sqlx::{
+        sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+        Decode,
+        Encode,
+        Sqlite,
+        Type,
+        ValueRef,
+    };
+    use super::{from_seconds, from_text, BoxDynError, IsNull};
+    use crate::UnixTimestamp;
+
+    impl Type<Sqlite> for UnixTimestamp {
+        fn type_info() -> SqliteTypeInfo {
+            <i64 as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <i64 as Type<Sqlite>>::compatible(ty) || <str as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for UnixTimestamp {
+        fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+            Encode::<Sqlite>::encode(self.unix_timestamp(), buf)
+        }
+    }
+
+    impl<'r> Decode<'r, Sqlite> for UnixTimestamp {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+            if <str as Type<Sqlite>>::compatible(&value.type_info()) {
+                from_text(<&str as Decode<Sqlite>>::decode(value)?)
+            } else {
+                from_seconds(<i64 as Decode<Sqlite>>::decode(value)?)
+            }
+        }
+    }
+}
@@ -1,5 +1,16 @@
-use std::time::{Duration, SystemTime};
-use super::UnixTimestamp;
+use std::{
+    env,
+    fs,
+    io,
+    path::{Component, Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+#[cfg(feature = "tzdb")]
+use super::StaticTimeZone;
+use super::{LeapSecondList, PosixTimeZone, TimeZone, UnixTimestamp};
+
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+const LOCALTIME: &str = "/etc/localtime";
 
 pub fn system_time_now() -> UnixTimestamp {
     SystemTime::now().into()
@@ -21,3 +32,116 @@ impl From<UnixTimestamp> for SystemTime {
         Self::UNIX_EPOCH + Duration::from_secs(timestamp)
     }
 }
+
+pub fn read_time_zone(path: &Path) -> io::Result<TimeZone> {
+    let bytes = fs::read(path)?;
+    TimeZone::from_tzif(&bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn read_leap_second_list(path: &Path) -> io::Result<LeapSecondList> {
+    fs::read_to_string(path)?.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+// Names are relative paths below the zoneinfo directory, which can be overridden by `TZDIR`
+pub fn read_zoneinfo(name: &str) -> io::Result<TimeZone> {
+    read_zoneinfo_dir(&zoneinfo_dir(), name)
+}
+
+fn read_zoneinfo_dir(dir: &Path, name: &str) -> io::Result<TimeZone> {
+    let name = Path::new(name);
+    if name.as_os_str().is_empty() || !name.components().all(|component| matches!(component, Component::Normal(_))) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid time zone name"));
+    }
+    read_time_zone(&dir.join(name))
+}
+
+fn zoneinfo_dir() -> PathBuf {
+    env::var_os("TZDIR").map_or_else(|| PathBuf::from(ZONEINFO_DIR), PathBuf::from)
+}
+
+pub fn read_local_time_zone() -> io::Result<TimeZone> {
+    resolve_local_time_zone(env::var("TZ"), &zoneinfo_dir(), Path::new(LOCALTIME))
+}
+
+// `TZ` is read like POSIX systems do: unset means /etc/localtime, and otherwise it is an
+// absolute path, a zoneinfo name or a POSIX TZ string, optionally prefixed by ':'
+fn resolve_local_time_zone(tz: Result<String, env::VarError>, zoneinfo_dir: &Path, localtime: &Path) -> io::Result<TimeZone> {
+    match tz {
+        Ok(tz) => read_tz(&tz, zoneinfo_dir),
+        Err(env::VarError::NotPresent) => match read_time_zone(localtime) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(TimeZone::utc()),
+            result => result,
+        },
+        Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+    }
+}
+
+fn read_tz(tz: &str, zoneinfo_dir: &Path) -> io::Result<TimeZone> {
+    let name = tz.strip_prefix(':').unwrap_or(tz);
+    if name.is_empty() {
+        return Ok(TimeZone::utc());
+    }
+    if name.starts_with('/') {
+        return read_time_zone(Path::new(name));
+    }
+    let err = match read_zoneinfo_dir(zoneinfo_dir, name) {
+        Ok(zone) => return Ok(zone),
+        Err(err) => err,
+    };
+    #[cfg(feature = "tzdb")]
+    {
+        if let Some(zone) = StaticTimeZone::from_name(name) {
+            return Ok(zone.into());
+        }
+    }
+    name.parse::<PosixTimeZone>().map(TimeZone::from).map_err(|_| err)
+}
+
+// The environment is process wide, so `TZ` and `TZDIR` are passed in instead of set
+#[cfg(test)]
+mod tests {
+    use std::{env::VarError, ffi::OsString, path::Path};
+    use super::{read_time_zone, read_zoneinfo_dir, resolve_local_time_zone, PosixTimeZone, TimeZone};
+
+    const ZONEINFO_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo");
+
+    fn zoneinfo(name: &str) -> TimeZone {
+        read_time_zone(&Path::new(ZONEINFO_DIR).join(name)).unwrap()
+    }
+
+    #[test]
+    fn zoneinfo_dir() {
+        assert_eq!(read_zoneinfo_dir(Path::new(ZONEINFO_DIR), "America/New_York").unwrap(), zoneinfo("America/New_York"));
+        for &name in &["", "/etc/localtime", "../zoneinfo/UTC", "America/../UTC", "Mars/Olympus_Mons"] {
+            assert!(read_zoneinfo_dir(Path::new(ZONEINFO_DIR), name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn local_time_zone() {
+        let dir = Path::new(ZONEINFO_DIR);
+        let kolkata = Path::new(ZONEINFO_DIR).join("Asia/Kolkata");
+        let posix = TimeZone::from("EST5EDT,M3.2.0,M11.1.0".parse::<PosixTimeZone>().unwrap());
+        let test_cases = [
+            ("America/New_York", zoneinfo("America/New_York")),
+            (":Europe/London", zoneinfo("Europe/London")),
+            (kolkata.to_str().unwrap(), zoneinfo("Asia/Kolkata")),
+            ("EST5EDT,M3.2.0,M11.1.0", posix),
+            ("", TimeZone::utc()),
+            (":", TimeZone::utc()),
+        ];
+
+        for (tz, zone) in test_cases.iter() {
+            assert_eq!(&resolve_local_time_zone(Ok(String::from(*tz)), dir, &kolkata).unwrap(), zone, "{}", tz);
+        }
+        for &tz in &["Mars/Olympus_Mons", "/nonexistent", "EST5EDT,M3.2.0"] {
+            assert!(resolve_local_time_zone(Ok(String::from(tz)), dir, &kolkata).is_err(), "{}", tz);
+        }
+
+        // Unset `TZ` falls back to /etc/localtime, and then to UTC if it doesn't exist
+        assert_eq!(resolve_local_time_zone(Err(VarError::NotPresent), dir, &kolkata).unwrap(), zoneinfo("Asia/Kolkata"));
+        assert_eq!(resolve_local_time_zone(Err(VarError::NotPresent), dir, Path::new("/nonexistent")).unwrap(), TimeZone::utc());
+        assert!(resolve_local_time_zone(Err(VarError::NotPresent), dir, dir).is_err());
+        assert!(resolve_local_time_zone(Err(VarError::NotUnicode(OsString::from("UTC0"))), dir, &kolkata).is_err());
+    }
+}
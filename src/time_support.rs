@@ -0,0 +1,79 @@
+use core::convert::TryFrom;
+use time::{error::ComponentRange, Date, OffsetDateTime, PrimitiveDateTime};
+use super::{result::TimestampResult, util, UnixTimestamp};
+
+const TIME_WEEKDAYS: [time::Weekday; 7] = [
+    time::Weekday::Monday,
+    time::Weekday::Tuesday,
+    time::Weekday::Wednesday,
+    time::Weekday::Thursday,
+    time::Weekday::Friday,
+    time::Weekday::Saturday,
+    time::Weekday::Sunday,
+];
+
+// Without the `large-dates` feature of `time`, only `UnixTimestamp::MAX` is out of range
+impl TryFrom<UnixTimestamp> for OffsetDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        OffsetDateTime::from_unix_timestamp(timestamp.unix_timestamp())
+    }
+}
+
+// In UTC
+impl TryFrom<UnixTimestamp> for PrimitiveDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        let date_time = OffsetDateTime::try_from(timestamp)?;
+        Ok(PrimitiveDateTime::new(date_time.date(), date_time.time()))
+    }
+}
+
+// The UTC date of the timestamp
+impl TryFrom<UnixTimestamp> for Date {
+    type Error = ComponentRange;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        Date::from_julian_day(timestamp.julian_day_number())
+    }
+}
+
+impl TryFrom<OffsetDateTime> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp(date_time.unix_timestamp()).into_result()
+    }
+}
+
+// Read as UTC
+impl TryFrom<PrimitiveDateTime> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Self::try_from(date_time.assume_utc())
+    }
+}
+
+// Midnight UTC of the date
+impl TryFrom<Date> for UnixTimestamp {
+    type Error = TimestampResult;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        UnixTimestamp::from_unix_timestamp((date.to_julian_day() - util::UNIX_EPOCH_JULIAN_DAY_NUMBER) as i64 * util::SECONDS_PER_DAY).into_result()
+    }
+}
+
+impl From<util::Weekday> for time::Weekday {
+    fn from(weekday: util::Weekday) -> Self {
+        TIME_WEEKDAYS[weekday.number_days_from_monday() as usize]
+    }
+}
+
+impl From<time::Weekday> for util::Weekday {
+    fn from(weekday: time::Weekday) -> Self {
+        util::Weekday::ALL[weekday.number_days_from_monday() as usize]
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::str;
+use super::UtcOffset;
+#[cfg(feature = "alloc")]
+use super::{
+    local_result::{self, LocalResult},
+    tzif,
+    OffsetTimestamp,
+    PosixTimeZone,
+    UnixTimestamp,
+};
+
+const MAX_ABBREVIATION_LEN: usize = 15;
+
+// Abbreviations are stored inline so local time types stay `Copy` and usable without `alloc`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocalTimeType {
+    offset: UtcOffset,
+    is_dst: bool,
+    abbreviation: [u8; MAX_ABBREVIATION_LEN],
+    abbreviation_len: u8,
+}
+
+impl LocalTimeType {
+    pub const UTC: LocalTimeType = Self {
+        offset: UtcOffset::UTC,
+        is_dst: false,
+        abbreviation: *b"UTC\0\0\0\0\0\0\0\0\0\0\0\0",
+        abbreviation_len: 3,
+    };
+
+    // Abbreviations are limited to printable ASCII, e.g. "EST" or "+0530"
+    pub const fn checked_new(offset: UtcOffset, is_dst: bool, abbreviation: &[u8]) -> Option<Self> {
+        if abbreviation.len() > MAX_ABBREVIATION_LEN {
+            return None;
+        }
+        let mut buffer = [0; MAX_ABBREVIATION_LEN];
+        let mut i = 0;
+        while i < abbreviation.len() {
+            if !abbreviation[i].is_ascii_graphic() {
+                return None;
+            }
+            buffer[i] = abbreviation[i];
+            i += 1;
+        }
+        Some(Self { offset, is_dst, abbreviation: buffer, abbreviation_len: abbreviation.len() as u8 })
+    }
+
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    pub const fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    pub fn abbreviation(&self) -> &str {
+        // Only ASCII is accepted by `checked_new`
+        str::from_utf8(&self.abbreviation[..self.abbreviation_len as usize]).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) struct Transition {
+    pub(super) timestamp: i64,
+    pub(super) local_time_type: usize,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeZone {
+    transitions: Vec<Transition>,
+    local_time_types: Vec<LocalTimeType>,
+    footer: Option<PosixTimeZone>,
+}
+
+#[cfg(feature = "alloc")]
+impl TimeZone {
+    pub fn utc() -> Self {
+        Self { transitions: Vec::new(), local_time_types: alloc::vec![LocalTimeType::UTC], footer: None }
+    }
+
+    // `local_time_types` must not be empty and transitions must be sorted with valid indices
+    pub(super) fn new(transitions: Vec<Transition>, local_time_types: Vec<LocalTimeType>, footer: Option<PosixTimeZone>) -> Self {
+        Self { transitions, local_time_types, footer }
+    }
+
+    pub fn from_tzif(bytes: &[u8]) -> Result<Self, tzif::TzifError> {
+        tzif::parse(bytes)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        super::std_support::read_time_zone(path.as_ref())
+    }
+
+    // The zone of the host, from the `TZ` environment variable or /etc/localtime
+    #[cfg(feature = "std")]
+    pub fn local() -> std::io::Result<Self> {
+        super::std_support::read_local_time_zone()
+    }
+
+    // Loads `name`, e.g. "America/New_York", from the system zoneinfo directory
+    #[cfg(feature = "std")]
+    pub fn from_zoneinfo(name: &str) -> std::io::Result<Self> {
+        super::std_support::read_zoneinfo(name)
+    }
+
+    pub fn local_time_types(&self) -> &[LocalTimeType] {
+        &self.local_time_types
+    }
+
+    // The TZ string footer of version 2+ files, which describes times after the last transition
+    pub fn footer(&self) -> Option<&PosixTimeZone> {
+        self.footer.as_ref()
+    }
+
+    pub fn transitions(&self) -> impl DoubleEndedIterator<Item = (i64, &LocalTimeType)> + ExactSizeIterator + '_ {
+        self.transitions.iter().map(move |transition| (transition.timestamp, &self.local_time_types[transition.local_time_type]))
+    }
+
+    // Times before the first transition use the first local time type, and
+    // times from the last transition on use the footer when there is one
+    pub fn local_time_type(&self, timestamp: UnixTimestamp) -> &LocalTimeType {
+        let unix_timestamp = timestamp.unix_timestamp();
+        let index = self.transitions.partition_point(|transition| transition.timestamp <= unix_timestamp); // MSRV 1.52
+        if let Some(footer) = &self.footer {
+            if index == self.transitions.len() {
+                return footer.local_time_type(timestamp);
+            }
+        }
+        let local_time_type = match index.checked_sub(1) {
+            Some(index) => self.transitions[index].local_time_type,
+            None => 0,
+        };
+        &self.local_time_types[local_time_type]
+    }
+
+    pub fn offset(&self, timestamp: UnixTimestamp) -> UtcOffset {
+        self.local_time_type(timestamp).offset()
+    }
+
+    pub fn to_offset_timestamp(&self, timestamp: UnixTimestamp) -> OffsetTimestamp {
+        OffsetTimestamp::new(timestamp, self.offset(timestamp))
+    }
+
+    pub fn checked_from_local_timestamp(&self, local_timestamp: i64) -> Option<LocalResult> {
+        local_result::from_local_timestamp(
+            local_timestamp,
+            self.local_time_types
+                .iter()
+                .chain(self.footer.iter().flat_map(PosixTimeZone::local_time_types))
+                .map(LocalTimeType::offset),
+            |timestamp| self.offset(timestamp),
+        )
+    }
+
+    pub fn checked_from_local_date_time(&self, year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<LocalResult> {
+        self.checked_from_local_timestamp(local_result::local_date_time_to_local_timestamp(year, month, day, hour, minute, second)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<PosixTimeZone> for TimeZone {
+    fn from(zone: PosixTimeZone) -> Self {
+        Self::new(Vec::new(), zone.local_time_types().copied().collect(), Some(zone))
+    }
+}
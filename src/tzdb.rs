@@ -0,0 +1,155 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use super::{time_zone::Transition, TimeZone};
+use super::{
+    local_result::{self, LocalResult},
+    posix_tz::DaylightSavingTime,
+    time_zone::LocalTimeType,
+    util::Weekday,
+    OffsetTimestamp,
+    PosixTimeZone,
+    RuleDay,
+    UnixTimestamp,
+    UtcOffset,
+};
+
+// The IANA time zone database, compiled by build.rs from tzdata/tzdata.zi and
+// trimmed to 1970 through 9999, so zones can be looked up without a filesystem
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StaticTimeZone {
+    name: &'static str,
+    transition_times: &'static [i64],
+    transition_types: &'static [u8],
+    local_time_types: &'static [LocalTimeType],
+    footer: PosixTimeZone,
+}
+
+include!(concat!(env!("OUT_DIR"), "/tzdb.rs"));
+
+// Only called in the generated constants, so invalid data from the build script fails
+// the build
+const fn local_time_type(offset: i32, is_dst: bool, abbreviation: &[u8]) -> LocalTimeType {
+    let offset = match UtcOffset::checked_from_seconds(offset) {
+        Some(offset) => offset,
+        None => panic!("invalid offset in generated tzdb"),
+    };
+    match LocalTimeType::checked_new(offset, is_dst, abbreviation) {
+        Some(local_time_type) => local_time_type,
+        None => panic!("invalid local time type in generated tzdb"),
+    }
+}
+
+// FNV-1a, seeded for the perfect hash generated by build.rs
+const fn hash(name: &[u8], seed: u32) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325 ^ seed as u64;
+    let mut i = 0;
+    while i < name.len() {
+        hash ^= name[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl StaticTimeZone {
+    // Release of the IANA time zone database, e.g. "2025b"
+    pub const VERSION: &'static str = VERSION;
+
+    // Links such as "US/Eastern" resolve to their zone, e.g. "America/New_York"
+    pub const fn from_name(name: &str) -> Option<&'static Self> {
+        let name = name.as_bytes();
+        let seed = SEEDS[(hash(name, 0) % SEEDS.len() as u64) as usize];
+        let (candidate, index) = NAMES[(hash(name, seed) % NAMES.len() as u64) as usize];
+        if eq(candidate.as_bytes(), name) {
+            Some(&ZONES[index as usize])
+        } else {
+            None
+        }
+    }
+
+    // Zone names without links
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        ZONES.iter().map(|zone| zone.name)
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn local_time_types(&self) -> &'static [LocalTimeType] {
+        self.local_time_types
+    }
+
+    pub const fn footer(&self) -> &PosixTimeZone {
+        &self.footer
+    }
+
+    pub fn transitions(&self) -> impl DoubleEndedIterator<Item = (i64, &LocalTimeType)> + ExactSizeIterator + '_ {
+        self.transition_times
+            .iter()
+            .zip(self.transition_types)
+            .map(move |(&timestamp, &index)| (timestamp, &self.local_time_types[index as usize]))
+    }
+
+    // Times from 1/1/1970 until the first transition use the first local time type,
+    // and times from the last transition on use the footer
+    pub fn local_time_type(&self, timestamp: UnixTimestamp) -> &LocalTimeType {
+        let unix_timestamp = timestamp.unix_timestamp();
+        let index = self.transition_times.partition_point(|&transition| transition <= unix_timestamp); // MSRV 1.52
+        if index == self.transition_times.len() {
+            return self.footer.local_time_type(timestamp);
+        }
+        match index.checked_sub(1) {
+            Some(index) => &self.local_time_types[self.transition_types[index] as usize],
+            None => &self.local_time_types[0],
+        }
+    }
+
+    pub fn offset(&self, timestamp: UnixTimestamp) -> UtcOffset {
+        self.local_time_type(timestamp).offset()
+    }
+
+    pub fn to_offset_timestamp(&self, timestamp: UnixTimestamp) -> OffsetTimestamp {
+        OffsetTimestamp::new(timestamp, self.offset(timestamp))
+    }
+
+    pub fn checked_from_local_timestamp(&self, local_timestamp: i64) -> Option<LocalResult> {
+        local_result::from_local_timestamp(
+            local_timestamp,
+            self.local_time_types.iter().chain(self.footer.local_time_types()).map(LocalTimeType::offset),
+            |timestamp| self.offset(timestamp),
+        )
+    }
+
+    pub fn checked_from_local_date_time(&self, year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<LocalResult> {
+        self.checked_from_local_timestamp(local_result::local_date_time_to_local_timestamp(year, month, day, hour, minute, second)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&StaticTimeZone> for TimeZone {
+    fn from(zone: &StaticTimeZone) -> Self {
+        let transitions = zone
+            .transition_times
+            .iter()
+            .zip(zone.transition_types)
+            .map(|(&timestamp, &local_time_type)| Transition { timestamp, local_time_type: local_time_type as usize })
+            .collect();
+        TimeZone::new(transitions, Vec::from(zone.local_time_types), Some(zone.footer))
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+use core::{fmt, str};
+use super::{
+    time_zone::{LocalTimeType, TimeZone, Transition},
+    PosixTimeZone,
+    UtcOffset,
+};
+
+// [RFC 8536](https://www.rfc-editor.org/rfc/rfc8536.html)
+const MAGIC: &[u8] = b"TZif";
+const HEADER_LEN: usize = 44;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TzifError {
+    InvalidHeader,
+    UnsupportedVersion(u8),
+    UnexpectedEof,
+    InvalidTransition,
+    InvalidLocalTimeType,
+    InvalidLeapSecond,
+    InvalidFooter,
+}
+
+impl fmt::Display for TzifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => f.write_str("invalid TZif header"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported TZif version {}", version),
+            Self::UnexpectedEof => f.write_str("unexpected end of TZif data"),
+            Self::InvalidTransition => f.write_str("invalid TZif transition"),
+            Self::InvalidLocalTimeType => f.write_str("invalid TZif local time type"),
+            Self::InvalidLeapSecond => f.write_str("invalid TZif leap second record"),
+            Self::InvalidFooter => f.write_str("invalid TZif footer"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TzifError {}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TzifError> {
+        if len > self.bytes.len() {
+            return Err(TzifError::UnexpectedEof);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, TzifError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, TzifError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn i32(&mut self) -> Result<i32, TzifError> {
+        Ok(self.u32()? as i32)
+    }
+
+    fn i64(&mut self) -> Result<i64, TzifError> {
+        let bytes = self.take(8)?;
+        let mut buffer = [0; 8];
+        buffer.copy_from_slice(bytes);
+        Ok(i64::from_be_bytes(buffer))
+    }
+
+    // Version 1 data blocks use 32-bit times, later versions use 64-bit times
+    fn time(&mut self, time_len: usize) -> Result<i64, TzifError> {
+        if time_len == 4 {
+            Ok(self.i32()? as i64)
+        } else {
+            self.i64()
+        }
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn parse(cursor: &mut Cursor<'_>) -> Result<Self, TzifError> {
+        if cursor.bytes.len() < HEADER_LEN || cursor.take(4)? != MAGIC {
+            return Err(TzifError::InvalidHeader);
+        }
+        let version = match cursor.u8()? {
+            0 => 1,
+            version @ b'2'..=b'4' => version - b'0',
+            version => return Err(TzifError::UnsupportedVersion(version)),
+        };
+        cursor.take(15)?;
+        let header = Self {
+            version,
+            isutcnt: cursor.u32()? as usize,
+            isstdcnt: cursor.u32()? as usize,
+            leapcnt: cursor.u32()? as usize,
+            timecnt: cursor.u32()? as usize,
+            typecnt: cursor.u32()? as usize,
+            charcnt: cursor.u32()? as usize,
+        };
+        let is_valid_indicator_count = |count| count == 0 || count == header.typecnt;
+        if header.typecnt == 0 || header.charcnt == 0 || !is_valid_indicator_count(header.isutcnt) || !is_valid_indicator_count(header.isstdcnt) {
+            return Err(TzifError::InvalidHeader);
+        }
+        Ok(header)
+    }
+
+    // Counts are untrusted, so a length that overflows is treated as running past the end
+    fn data_block_len(&self, time_len: usize) -> Result<usize, TzifError> {
+        let counts = [(self.timecnt, time_len + 1), (self.typecnt, 6), (self.charcnt, 1), (self.leapcnt, time_len + 4), (self.isstdcnt, 1), (self.isutcnt, 1)];
+        counts
+            .iter()
+            .try_fold(0usize, |len, &(count, size)| count.checked_mul(size).and_then(|block_len| len.checked_add(block_len)))
+            .ok_or(TzifError::UnexpectedEof)
+    }
+}
+
+pub(super) fn parse(bytes: &[u8]) -> Result<TimeZone, TzifError> {
+    let mut cursor = Cursor { bytes };
+    let header = Header::parse(&mut cursor)?;
+    // Version 2+ files repeat the data with 64-bit times after the version 1 block
+    let (header, time_len) = if header.version >= 2 {
+        cursor.take(header.data_block_len(4)?)?;
+        let v2_header = Header::parse(&mut cursor)?;
+        if v2_header.version != header.version {
+            return Err(TzifError::InvalidHeader);
+        }
+        (v2_header, 8)
+    } else {
+        (header, 4)
+    };
+    // Checked before allocating for the counts in the header
+    if cursor.bytes.len() < header.data_block_len(time_len)? {
+        return Err(TzifError::UnexpectedEof);
+    }
+
+    let mut transitions: Vec<Transition> = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        let timestamp = cursor.time(time_len)?;
+        if let Some(last) = transitions.last() {
+            if last.timestamp >= timestamp {
+                return Err(TzifError::InvalidTransition);
+            }
+        }
+        transitions.push(Transition { timestamp, local_time_type: 0 });
+    }
+    for transition in transitions.iter_mut() {
+        transition.local_time_type = cursor.u8()? as usize;
+        if transition.local_time_type >= header.typecnt {
+            return Err(TzifError::InvalidTransition);
+        }
+    }
+
+    let mut records = Vec::with_capacity(header.typecnt);
+    for _ in 0..header.typecnt {
+        let offset = UtcOffset::checked_from_seconds(cursor.i32()?).ok_or(TzifError::InvalidLocalTimeType)?;
+        let is_dst = match cursor.u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(TzifError::InvalidLocalTimeType),
+        };
+        records.push((offset, is_dst, cursor.u8()? as usize));
+    }
+    let designations = cursor.take(header.charcnt)?;
+    let mut local_time_types = Vec::with_capacity(header.typecnt);
+    for (offset, is_dst, index) in records {
+        let designation = designations.get(index..).ok_or(TzifError::InvalidLocalTimeType)?;
+        let len = designation.iter().position(|&byte| byte == 0).ok_or(TzifError::InvalidLocalTimeType)?;
+        let local_time_type = LocalTimeType::checked_new(offset, is_dst, &designation[..len]).ok_or(TzifError::InvalidLocalTimeType)?;
+        local_time_types.push(local_time_type);
+    }
+
+    // Transition times of zones with leap seconds count the inserted seconds,
+    // so they are corrected back to unix time
+    let mut leap_seconds = Vec::with_capacity(header.leapcnt);
+    for _ in 0..header.leapcnt {
+        let occurrence = cursor.time(time_len)?;
+        let correction = cursor.i32()?;
+        if let Some(&(last, _)) = leap_seconds.last() {
+            if last >= occurrence {
+                return Err(TzifError::InvalidLeapSecond);
+            }
+        }
+        leap_seconds.push((occurrence, correction));
+    }
+    if !leap_seconds.is_empty() {
+        for transition in transitions.iter_mut() {
+            let index = leap_seconds.partition_point(|&(occurrence, _)| occurrence <= transition.timestamp);
+            if let Some(&(_, correction)) = index.checked_sub(1).and_then(|index| leap_seconds.get(index)) {
+                transition.timestamp = transition.timestamp.checked_sub(correction as i64).ok_or(TzifError::InvalidLeapSecond)?;
+            }
+        }
+        // Corrections are untrusted too, and lookups need the transitions in order
+        if transitions.windows(2).any(|pair| pair[0].timestamp >= pair[1].timestamp) {
+            return Err(TzifError::InvalidLeapSecond);
+        }
+    }
+
+    // Standard/wall and UT/local indicators only matter for POSIX TZ strings without rules
+    cursor.take(header.isstdcnt + header.isutcnt)?;
+
+    let footer = if header.version >= 2 { parse_footer(&mut cursor)? } else { None };
+    Ok(TimeZone::new(transitions, local_time_types, footer))
+}
+
+// An empty footer means times after the last transition have no rule
+fn parse_footer(cursor: &mut Cursor<'_>) -> Result<Option<PosixTimeZone>, TzifError> {
+    if cursor.u8()? != b'\n' {
+        return Err(TzifError::InvalidFooter);
+    }
+    let len = cursor.bytes.iter().position(|&byte| byte == b'\n').ok_or(TzifError::InvalidFooter)?;
+    let footer = str::from_utf8(cursor.take(len)?).map_err(|_| TzifError::InvalidFooter)?;
+    if !footer.is_ascii() {
+        return Err(TzifError::InvalidFooter);
+    }
+    cursor.take(1)?;
+    if footer.is_empty() {
+        return Ok(None);
+    }
+    footer.parse().map(Some).map_err(|_| TzifError::InvalidFooter)
+}
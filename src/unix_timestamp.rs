@@ -1,9 +1,13 @@
 use super::{
+    business_day,
     result,
     util,
+    BusinessCalendar,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+// `repr(transparent)` so slices of timestamps can be viewed as slices of `i64`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct UnixTimestamp(i64);
 
 impl UnixTimestamp {
@@ -19,6 +23,18 @@ impl UnixTimestamp {
         super::std_support::system_time_now()
     }
 
+    // Faster than `now` on Linux, at the cost of lagging by up to a scheduler tick
+    #[cfg(all(feature = "libc", unix))]
+    pub fn now_coarse() -> Self {
+        super::libc_support::clock_gettime_coarse()
+    }
+
+    // The current time in the zone of `TimeZone::local`
+    #[cfg(feature = "std")]
+    pub fn now_local() -> std::io::Result<super::OffsetTimestamp> {
+        Ok(super::TimeZone::local()?.to_offset_timestamp(Self::now()))
+    }
+
     pub const fn checked_from_unix_timestamp(timestamp: i64) -> Option<Self> {
         Self::from_unix_timestamp(timestamp).ok()
     }
@@ -74,38 +90,13 @@ impl UnixTimestamp {
     // Only valid for dates after the year 0 defined by ISO 8601
     // [section 2.2.1](https://www.researchgate.net/publication/316558298_Date_Algorithms)
     pub const fn from_year_month_day(year: u16, month: u8, day: u8) -> result::TimestampResult {
-        let (adj_year, adj_month, day) = if month < 3 {
-            (year as i32 + 399, month as i32 + 12, day as i32)
-        } else {
-            (year as i32 + 400, month as i32, day as i32)
-        };
-        // f = (153 * adj_month - 457) / 5
-        let f = (979 * adj_month - 2_918) >> 5;
-        let julian_day_number = day + f + 365 * adj_year + adj_year / 4 - adj_year / 100 + adj_year / 400 + 1_575_022;
-        Self::from_julian_day_number(julian_day_number)
+        Self::from_julian_day_number(year_month_day_to_julian_day_number(year, month, day))
     }
 
     // Only valid for dates after the year 0 defined by ISO 8601
     // [section 3.2.1/3.3.1](https://www.researchgate.net/publication/316558298_Date_Algorithms)
     pub const fn to_year_month_day(self) -> (u16, u8, u8) {
-        let julian_day_number = self.julian_day_number() as u32;
-        let z = julian_day_number - 1_575_022;
-        let h = 100 * z - 25;
-        let a = h / 3_652_425;
-        let b = a - a / 4;
-        let adj_year = (100 * b + h) / 36_525;
-        let c = b + z - 365 * adj_year - adj_year / 4;
-        // adj_month = (5 * c + 456) / 153
-        let adj_month = (535 * c + 48_950) >> 14;
-        // f = (153 * adj_month - 457) / 5
-        let f = (979 * adj_month - 2_918) >> 5;
-        let day = c - f;
-        let (year, month) = if adj_month > 12 {
-            (adj_year - 399, adj_month - 12)
-        } else {
-            (adj_year - 400, adj_month)
-        };
-        (year as u16, month as u8, day as u8)
+        julian_day_number_to_year_month_day(self.julian_day_number())
     }
 
     pub const fn checked_from_year_ordinal(year: u16, ordinal: u16) -> Option<Self> {
@@ -134,14 +125,7 @@ impl UnixTimestamp {
     // [Eliminating the Lookup Table](https://blog.reverberate.org/2020/05/12/optimizing-date-algorithms.html)
     pub const fn to_year_ordinal(self) -> (u16, u16) {
         let (year, month, day) = self.to_year_month_day();
-        let (month, day) = (month as u64, day as u64);
-        // f = (306 * adj_month + 5) / 10
-        let ordinal = if month >= 3 {
-            ((979 * (month - 3) + 16) >> 5) + day + 59 + util::is_leap_year(year) as u64
-        } else {
-            ((979 * (month + 9) + 16) >> 5) + day - 306
-        };
-        (year, ordinal as u16)
+        (year, year_month_day_to_ordinal(year, month, day))
     }
 
     pub const fn checked_from_julian_day_number(julian_day_number: i32) -> Option<Self> {
@@ -163,4 +147,68 @@ impl UnixTimestamp {
         let wd = adj_days - (((adj_days * 613_566_757) >> 32) * 7);
         util::Weekday::new(wd)
     }
+
+    pub fn is_business_day(self, calendar: &BusinessCalendar<'_>) -> bool {
+        business_day::is_business_day(self, calendar)
+    }
+
+    pub fn add_business_days(self, days: i64, calendar: &BusinessCalendar<'_>) -> Option<Self> {
+        business_day::add_business_days(self, days, calendar)
+    }
+
+    pub fn next_business_day(self, calendar: &BusinessCalendar<'_>) -> Option<Self> {
+        self.add_business_days(1, calendar)
+    }
+
+    pub fn previous_business_day(self, calendar: &BusinessCalendar<'_>) -> Option<Self> {
+        self.add_business_days(-1, calendar)
+    }
+
+    pub fn business_days_between(start: Self, end: Self, calendar: &BusinessCalendar<'_>) -> i64 {
+        business_day::business_days_between(start, end, calendar)
+    }
+}
+
+// Shared with wall-clock conversions that may fall just outside the supported range
+pub(super) const fn year_month_day_to_julian_day_number(year: u16, month: u8, day: u8) -> i32 {
+    let (adj_year, adj_month, day) = if month < 3 {
+        (year as i32 + 399, month as i32 + 12, day as i32)
+    } else {
+        (year as i32 + 400, month as i32, day as i32)
+    };
+    // f = (153 * adj_month - 457) / 5
+    let f = (979 * adj_month - 2_918) >> 5;
+    day + f + 365 * adj_year + adj_year / 4 - adj_year / 100 + adj_year / 400 + 1_575_022
+}
+
+pub(super) const fn julian_day_number_to_year_month_day(julian_day_number: i32) -> (u16, u8, u8) {
+    let julian_day_number = julian_day_number as u32;
+    let z = julian_day_number - 1_575_022;
+    let h = 100 * z - 25;
+    let a = h / 3_652_425;
+    let b = a - a / 4;
+    let adj_year = (100 * b + h) / 36_525;
+    let c = b + z - 365 * adj_year - adj_year / 4;
+    // adj_month = (5 * c + 456) / 153
+    let adj_month = (535 * c + 48_950) >> 14;
+    // f = (153 * adj_month - 457) / 5
+    let f = (979 * adj_month - 2_918) >> 5;
+    let day = c - f;
+    let (year, month) = if adj_month > 12 {
+        (adj_year - 399, adj_month - 12)
+    } else {
+        (adj_year - 400, adj_month)
+    };
+    (year as u16, month as u8, day as u8)
+}
+
+// f = (306 * adj_month + 5) / 10
+pub(super) const fn year_month_day_to_ordinal(year: u16, month: u8, day: u8) -> u16 {
+    let (month, day) = (month as u64, day as u64);
+    let ordinal = if month >= 3 {
+        ((979 * (month - 3) + 16) >> 5) + day + 59 + util::is_leap_year(year) as u64
+    } else {
+        ((979 * (month + 9) + 16) >> 5) + day - 306
+    };
+    ordinal as u16
 }
@@ -1,5 +1,6 @@
 use super::UnixTimestamp;
-pub use super::weekday::Weekday;
+pub use super::weekday::{Weekday, WeekdayIter, WeekdayNumberError};
+pub use super::weekday_set::{ParseWeekdaySetError, WeekdaySet, WeekdaySetIter};
 
 pub const SECONDS_PER_DAY: i64 = 86_400;
 pub const UNIX_EPOCH_JULIAN_DAY_NUMBER: i32 = 2_440_588;
@@ -17,6 +18,7 @@ pub const fn is_valid_year_ordinal(year: u16, ordinal: u16) -> bool {
     1 <= ordinal && ordinal <= days_in_year(year)
 }
 
+#[allow(clippy::manual_is_multiple_of)] // is_multiple_of is MSRV 1.87
 pub const fn is_leap_year(year: u16) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
@@ -33,3 +35,33 @@ pub const fn days_in_year_month(year: u16, month: u8) -> u8 {
 pub const fn days_in_year(year: u16) -> u16 {
     365 + is_leap_year(year) as u16
 }
+
+// Anonymous Gregorian algorithm (Meeus/Jones/Butcher)
+// `from_year_month_day` is linear in `day`, so days past March 31 roll into April
+pub const fn easter_sunday(year: u16) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, 3, 22 + easter_days_after_march_22(year)).unwrap()
+}
+
+pub const fn good_friday(year: u16) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, 3, 20 + easter_days_after_march_22(year)).unwrap()
+}
+
+pub const fn ascension_day(year: u16) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, 3, 61 + easter_days_after_march_22(year)).unwrap()
+}
+
+pub const fn pentecost(year: u16) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, 3, 71 + easter_days_after_march_22(year)).unwrap()
+}
+
+const fn easter_days_after_march_22(year: u16) -> u8 {
+    let year = year as u32;
+    let golden = year % 19;
+    let (century, year_of_century) = (year / 100, year % 100);
+    let f = (century + 8) / 25;
+    let g = (century - f + 1) / 3;
+    let epact = (19 * golden + century - century / 4 - g + 15) % 30;
+    let l = (32 + 2 * (century % 4) + 2 * (year_of_century / 4) - epact - year_of_century % 4) % 7;
+    let m = (golden + 11 * epact + 22 * l) / 451;
+    (epact + l - 7 * m) as u8
+}
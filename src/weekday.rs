@@ -1,4 +1,10 @@
-#[derive(Clone, Copy, Debug, PartialEq)]
+use core::{
+    convert::TryFrom,
+    fmt,
+    iter::FusedIterator,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Weekday(u64);
 
 impl Weekday {
@@ -10,6 +16,16 @@ impl Weekday {
     pub const SATURDAY: Weekday = Self::new(5);
     pub const SUNDAY: Weekday = Self::new(6);
 
+    pub const ALL: [Weekday; 7] = [
+        Self::MONDAY,
+        Self::TUESDAY,
+        Self::WEDNESDAY,
+        Self::THURSDAY,
+        Self::FRIDAY,
+        Self::SATURDAY,
+        Self::SUNDAY,
+    ];
+
     pub(super) const fn new(value: u64) -> Self {
         Self(value)
     }
@@ -42,4 +58,107 @@ impl Weekday {
     pub const fn days_until(self, later: Self) -> u64 {
         later.days_since(self)
     }
+
+    pub const fn nth_next(self, n: u64) -> Self {
+        Self::new((self.0 + n % 7) % 7)
+    }
+
+    pub const fn nth_previous(self, n: u64) -> Self {
+        Self::new((self.0 + 7 - n % 7) % 7)
+    }
+
+    // ISO 8601 numbering, Monday is 1 and Sunday is 7
+    pub const fn number_from_monday(self) -> u8 {
+        self.0 as u8 + 1
+    }
+
+    pub const fn number_from_sunday(self) -> u8 {
+        self.number_days_from_sunday() + 1
+    }
+
+    pub const fn number_days_from_monday(self) -> u8 {
+        self.0 as u8
+    }
+
+    pub const fn number_days_from_sunday(self) -> u8 {
+        self.next().0 as u8
+    }
+
+    pub const fn from_iso_number(number: u8) -> Option<Self> {
+        if 1 <= number && number <= 7 {
+            Some(Self::new(number as u64 - 1))
+        } else {
+            None
+        }
+    }
+
+    // The 7 days of the week starting at `self`
+    pub const fn iter_from(self) -> WeekdayIter {
+        WeekdayIter { start: self, front: 0, back: 7 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekdayNumberError(u8);
+
+impl fmt::Display for WeekdayNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "weekday number {} is not between 1 and 7", self.0)
+    }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeekdayNumberError {}
+
+// Uses ISO 8601 numbering like `from_iso_number`
+impl TryFrom<u8> for Weekday {
+    type Error = WeekdayNumberError;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        Self::from_iso_number(number).ok_or(WeekdayNumberError(number))
+    }
+}
+
+impl From<Weekday> for u8 {
+    fn from(weekday: Weekday) -> Self {
+        weekday.number_from_monday()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WeekdayIter {
+    start: Weekday,
+    front: u64,
+    back: u64,
+}
+
+impl Iterator for WeekdayIter {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Weekday> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.start.nth_next(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for WeekdayIter {
+    fn next_back(&mut self) -> Option<Weekday> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.start.nth_next(self.back))
+    }
+}
+
+impl ExactSizeIterator for WeekdayIter {}
+
+impl FusedIterator for WeekdayIter {}
@@ -0,0 +1,248 @@
+use core::{
+    fmt,
+    iter::FromIterator,
+    ops::{BitAnd, BitOr, Not},
+    str::FromStr,
+};
+use super::weekday::Weekday;
+
+const ALL_BITS: u8 = 0x7f;
+
+// Bit 0 is Monday through bit 6 for Sunday
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    pub const EMPTY: WeekdaySet = Self::new(0);
+    pub const ALL: WeekdaySet = Self::new(ALL_BITS);
+    pub const WEEKDAYS: WeekdaySet = Self::new(0x1f);
+    pub const WEEKEND: WeekdaySet = Self::new(0x60);
+
+    const fn new(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn checked_from_bits(bits: u8) -> Option<Self> {
+        if bits & !ALL_BITS == 0 {
+            Some(Self::new(bits))
+        } else {
+            None
+        }
+    }
+
+    pub const fn from_bits_truncate(bits: u8) -> Self {
+        Self::new(bits & ALL_BITS)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn single(weekday: Weekday) -> Self {
+        Self::new(1 << index(weekday))
+    }
+
+    pub const fn from_weekdays(weekdays: &[Weekday]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < weekdays.len() {
+            set = set.with(weekdays[i]);
+            i += 1;
+        }
+        set
+    }
+
+    // Inclusive range that wraps around the end of the week, e.g. Friday to Monday
+    pub const fn from_range(first: Weekday, last: Weekday) -> Self {
+        let len = first.days_until(last) + 1;
+        let bits = ((1u16 << len) - 1) << index(first);
+        Self::new(((bits | bits >> 7) & ALL_BITS as u16) as u8)
+    }
+
+    pub const fn with(self, weekday: Weekday) -> Self {
+        Self::new(self.0 | Self::single(weekday).0)
+    }
+
+    pub const fn without(self, weekday: Weekday) -> Self {
+        Self::new(self.0 & !Self::single(weekday).0)
+    }
+
+    pub const fn contains(self, weekday: Weekday) -> bool {
+        self.0 & Self::single(weekday).0 != 0
+    }
+
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self::new(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::new(self.0 & other.0)
+    }
+
+    pub const fn difference(self, other: Self) -> Self {
+        Self::new(self.0 & !other.0)
+    }
+
+    pub const fn complement(self) -> Self {
+        Self::new(!self.0 & ALL_BITS)
+    }
+
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    // Wraps around the week, so a set containing only `weekday` returns `weekday`
+    pub const fn next_in_set_after(self, weekday: Weekday) -> Option<Weekday> {
+        let shift = index(weekday) + 1;
+        let rotated = (self.0 >> shift | self.0 << (7 - shift)) & ALL_BITS;
+        if rotated == 0 {
+            return None;
+        }
+        Some(Weekday::new((shift + rotated.trailing_zeros()) as u64 % 7))
+    }
+
+    pub const fn previous_in_set_before(self, weekday: Weekday) -> Option<Weekday> {
+        let shift = 6 - index(weekday) + 1;
+        // Reverse rotation, so the day before `weekday` ends up in the highest bit
+        let rotated = (self.0 << shift | self.0 >> (7 - shift)) & ALL_BITS;
+        if rotated == 0 {
+            return None;
+        }
+        Some(Weekday::new((index(weekday) + 6 - (rotated.leading_zeros() - 1)) as u64 % 7))
+    }
+
+    pub const fn iter(self) -> WeekdaySetIter {
+        WeekdaySetIter(self.0)
+    }
+}
+
+const fn index(weekday: Weekday) -> u32 {
+    weekday.number_days_from_monday() as u32
+}
+
+#[derive(Clone, Debug)]
+pub struct WeekdaySetIter(u8);
+
+impl Iterator for WeekdaySetIter {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Weekday> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(Weekday::new(index as u64))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for WeekdaySetIter {}
+
+impl IntoIterator for WeekdaySet {
+    type Item = Weekday;
+    type IntoIter = WeekdaySetIter;
+
+    fn into_iter(self) -> WeekdaySetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<I: IntoIterator<Item = Weekday>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    fn from(weekday: Weekday) -> Self {
+        Self::single(weekday)
+    }
+}
+
+impl BitOr for WeekdaySet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for WeekdaySet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl Not for WeekdaySet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseWeekdaySetError;
+
+impl fmt::Display for ParseWeekdaySetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid weekday set")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseWeekdaySetError {}
+
+const NAMES: [(&str, &str); 7] = [
+    ("mon", "monday"),
+    ("tue", "tuesday"),
+    ("wed", "wednesday"),
+    ("thu", "thursday"),
+    ("fri", "friday"),
+    ("sat", "saturday"),
+    ("sun", "sunday"),
+];
+
+fn parse_weekday(name: &str) -> Result<Weekday, ParseWeekdaySetError> {
+    let name = name.trim();
+    NAMES
+        .iter()
+        .position(|(short, long)| name.eq_ignore_ascii_case(short) || name.eq_ignore_ascii_case(long))
+        .map(|index| Weekday::new(index as u64))
+        .ok_or(ParseWeekdaySetError)
+}
+
+// Comma separated weekdays or inclusive ranges, e.g. "Mon-Fri" or "Sat,Sun"
+impl FromStr for WeekdaySet {
+    type Err = ParseWeekdaySetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::EMPTY);
+        }
+        s.split(',').try_fold(Self::EMPTY, |set, item| {
+            let mut bounds = item.splitn(2, '-');
+            let first = parse_weekday(bounds.next().unwrap_or(""))?;
+            let range = match bounds.next() {
+                Some(last) => Self::from_range(first, parse_weekday(last)?),
+                None => Self::single(first),
+            };
+            Ok(set.union(range))
+        })
+    }
+}
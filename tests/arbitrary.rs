@@ -0,0 +1,36 @@
+#![cfg(feature = "arbitrary")]
+
+mod common;
+
+use arbitrary::{Arbitrary, Unstructured};
+use practicaltimestamp::{util::Weekday, UnixTimestamp};
+use common::{EdgeCases, Random};
+
+#[test]
+fn arbitrary() {
+    let mut random = Random(0);
+    let mut bytes = vec![0u8; 10_000];
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&random.next().to_le_bytes()[..chunk.len()]);
+    }
+
+    let mut u = Unstructured::new(&bytes);
+    let mut edge_cases = EdgeCases::default();
+    let mut weekdays = [false; 7];
+    for _ in 0..1_000 {
+        let timestamp = UnixTimestamp::arbitrary(&mut u).unwrap();
+        assert_eq!(UnixTimestamp::checked_from_unix_timestamp(timestamp.unix_timestamp()), Some(timestamp));
+        edge_cases.record(timestamp);
+        weekdays[Weekday::arbitrary(&mut u).unwrap().number_days_from_monday() as usize] = true;
+    }
+    assert!(edge_cases.all(), "{:?}", edge_cases);
+    assert_eq!(weekdays, [true; 7]);
+}
+
+#[test]
+fn exhausted() {
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(UnixTimestamp::arbitrary(&mut u).unwrap(), UnixTimestamp::MIN);
+    assert_eq!(UnixTimestamp::arbitrary_take_rest(Unstructured::new(&[4])).unwrap(), UnixTimestamp::MAX);
+    assert_eq!(<UnixTimestamp as Arbitrary>::size_hint(0), (9, Some(9)));
+}
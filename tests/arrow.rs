@@ -0,0 +1,58 @@
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use arrow_array::{Array, Date32Array, TimestampSecondArray};
+use practicaltimestamp::{arrow, ArrowTimestampError, UnixTimestamp};
+use common::ts;
+
+const TIMESTAMPS: &[i64] = &[0, 951_782_400, 1_615_705_200, 253_402_300_799, 253_402_300_800];
+
+#[test]
+fn timestamp_array() {
+    let timestamps: Vec<UnixTimestamp> = TIMESTAMPS.iter().copied().map(ts).collect();
+    let pointer = timestamps.as_ptr() as *const i64;
+    let array = arrow::timestamp_array(timestamps);
+    assert_eq!(array.values().as_ptr(), pointer);
+    assert_eq!(&array.values()[..], TIMESTAMPS);
+    assert_eq!(array.timezone(), Some("+00:00"));
+    assert_eq!(array.null_count(), 0);
+
+    let borrowed = arrow::timestamps(&array).unwrap();
+    assert_eq!(borrowed.as_ptr() as *const i64, pointer);
+    assert_eq!(borrowed, &TIMESTAMPS.iter().copied().map(ts).collect::<Vec<_>>()[..]);
+
+    let copied = arrow::timestamp_array_from_slice(borrowed);
+    assert_ne!(copied.values().as_ptr(), pointer);
+    assert_eq!(copied, array);
+}
+
+#[test]
+fn timestamps() {
+    // Values are read the same in any time zone
+    let array = TimestampSecondArray::from(TIMESTAMPS.to_vec()).with_timezone("America/New_York");
+    assert_eq!(arrow::timestamps(&array).unwrap().len(), TIMESTAMPS.len());
+
+    let array = TimestampSecondArray::from(vec![0, -1, 253_402_300_801]);
+    assert_eq!(arrow::timestamps(&array), Err(ArrowTimestampError::OutOfRange(-1)));
+    let array = TimestampSecondArray::from(vec![Some(0), Some(1), None, None]);
+    assert_eq!(arrow::timestamps(&array), Err(ArrowTimestampError::NullValue(2)));
+    assert_eq!(arrow::timestamps(&array.slice(0, 2)).unwrap(), &[ts(0), ts(1)]);
+    assert_eq!(ArrowTimestampError::NullValue(2).to_string(), "null timestamp at index 2");
+}
+
+#[test]
+fn date32() {
+    let timestamps: Vec<UnixTimestamp> = TIMESTAMPS.iter().copied().map(ts).collect();
+    let array = arrow::date32_array(&timestamps);
+    assert_eq!(&array.values()[..], &[0, 11_016, 18_700, 2_932_896, 2_932_897]);
+    assert_eq!(
+        arrow::timestamps_from_date32(&array),
+        Ok(vec![ts(0), ts(951_782_400), ts(1_615_680_000), ts(253_402_214_400), ts(253_402_300_800)]),
+    );
+
+    let array = Date32Array::from(vec![0, 2_932_898]);
+    assert_eq!(arrow::timestamps_from_date32(&array), Err(ArrowTimestampError::OutOfRange(253_402_387_200)));
+    let array = Date32Array::from(vec![None, Some(0)]);
+    assert_eq!(arrow::timestamps_from_date32(&array), Err(ArrowTimestampError::NullValue(0)));
+}
@@ -0,0 +1,120 @@
+use practicaltimestamp::{BusinessCalendar, HolidayCalendar, HolidayDate, HolidayRule, Observance, UnixTimestamp, util::{self, Weekday, WeekdaySet}};
+
+type YearMonthDay = (u16, u8, u8);
+
+const HOLIDAYS: &[HolidayRule] = &[
+    HolidayRule::new("New Year's Day", HolidayDate::Fixed { month: 1, day: 1 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Good Friday", HolidayDate::EasterOffset(-2)),
+    HolidayRule::new("Independence Day", HolidayDate::Fixed { month: 7, day: 4 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Thanksgiving Day", HolidayDate::NthWeekday { month: 11, weekday: Weekday::THURSDAY, nth: 4 }),
+    HolidayRule::new("Christmas Eve", HolidayDate::Fixed { month: 12, day: 24 }),
+    HolidayRule::new("Christmas Day", HolidayDate::Fixed { month: 12, day: 25 }).with_observance(Observance::NearestWeekday),
+];
+
+fn ymd(year: u16, month: u8, day: u8) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, month, day).unwrap()
+}
+
+fn calendars() -> Vec<BusinessCalendar<'static>> {
+    vec![
+        BusinessCalendar::default(),
+        BusinessCalendar::new("Fri,Sat".parse().unwrap()),
+        BusinessCalendar::new(WeekdaySet::single(Weekday::SUNDAY)),
+        BusinessCalendar::new(WeekdaySet::EMPTY),
+        BusinessCalendar::default().with_holidays(HolidayCalendar::new(HOLIDAYS)),
+        BusinessCalendar::new(WeekdaySet::single(Weekday::SUNDAY)).with_holidays(HolidayCalendar::new(HOLIDAYS)),
+    ]
+}
+
+// Reference implementation stepping one day at a time
+fn step_business_days(timestamp: UnixTimestamp, days: i64, calendar: &BusinessCalendar<'_>) -> Option<UnixTimestamp> {
+    let step = days.signum() * util::SECONDS_PER_DAY;
+    let mut timestamp = timestamp;
+    for _ in 0..days.abs() {
+        timestamp = timestamp.checked_add(step)?;
+        while !timestamp.is_business_day(calendar) {
+            timestamp = timestamp.checked_add(step)?;
+        }
+    }
+    Some(timestamp)
+}
+
+#[test]
+fn is_business_day() {
+    let calendar = BusinessCalendar::default().with_holidays(HolidayCalendar::new(HOLIDAYS));
+    const TEST_CASES: &[(YearMonthDay, bool)] = &[
+        ((2020, 12, 23), true),
+        ((2020, 12, 24), false),
+        ((2020, 12, 25), false),
+        ((2020, 12, 26), false),
+        ((2020, 12, 28), true),
+        ((2021, 7, 5), false),
+        ((2021, 12, 31), false),
+    ];
+
+    for &((y, m, d), expected) in TEST_CASES {
+        assert_eq!(ymd(y, m, d).is_business_day(&calendar), expected);
+    }
+    assert!(BusinessCalendar::default().is_weekend(Weekday::SUNDAY));
+    assert!(!BusinessCalendar::default().is_weekend(Weekday::FRIDAY));
+}
+
+#[test]
+fn settlement() {
+    let calendar = BusinessCalendar::default().with_holidays(HolidayCalendar::new(HOLIDAYS));
+    const TEST_CASES: &[(YearMonthDay, i64, YearMonthDay)] = &[
+        ((2020, 10, 7), 2, (2020, 10, 9)),
+        ((2020, 10, 8), 2, (2020, 10, 12)),
+        ((2020, 10, 10), 1, (2020, 10, 12)),
+        ((2020, 10, 12), -1, (2020, 10, 9)),
+        ((2020, 12, 23), 2, (2020, 12, 29)),
+        ((2021, 4, 1), 1, (2021, 4, 5)),
+        ((2021, 12, 30), 1, (2022, 1, 3)),
+        ((2020, 10, 7), 0, (2020, 10, 7)),
+    ];
+
+    for &((y, m, d), days, expected) in TEST_CASES {
+        assert_eq!(ymd(y, m, d).add_business_days(days, &calendar).map(UnixTimestamp::to_year_month_day), Some(expected));
+    }
+    let timestamp = ymd(2020, 10, 8).saturating_add(3_600);
+    assert_eq!(timestamp.next_business_day(&calendar).unwrap().seconds_since_midnight(), 3_600);
+    assert_eq!(timestamp.previous_business_day(&calendar).unwrap().to_year_month_day(), (2020, 10, 7));
+}
+
+#[test]
+fn matches_day_by_day_stepping() {
+    for calendar in &calendars() {
+        for start in (0..40).map(|offset| ymd(2020, 12, 1).saturating_add(offset * util::SECONDS_PER_DAY)) {
+            for days in -30..=30 {
+                assert_eq!(start.add_business_days(days, calendar), step_business_days(start, days, calendar));
+            }
+        }
+    }
+}
+
+#[test]
+fn business_days_between() {
+    for calendar in &calendars() {
+        let start = ymd(2020, 12, 20);
+        for offset in 0..60 {
+            let end = start.saturating_add(offset * util::SECONDS_PER_DAY);
+            let expected = (0..offset)
+                .filter(|&day| start.saturating_add(day * util::SECONDS_PER_DAY).is_business_day(calendar))
+                .count() as i64;
+            assert_eq!(UnixTimestamp::business_days_between(start, end, calendar), expected);
+            assert_eq!(UnixTimestamp::business_days_between(end, start, calendar), -expected);
+        }
+    }
+    let calendar = BusinessCalendar::default();
+    assert_eq!(UnixTimestamp::business_days_between(UnixTimestamp::MIN, UnixTimestamp::MAX, &calendar), 2_094_927);
+}
+
+#[test]
+fn out_of_range() {
+    let calendar = BusinessCalendar::default();
+    assert_eq!(UnixTimestamp::MIN.previous_business_day(&calendar), None);
+    assert_eq!(UnixTimestamp::MAX.next_business_day(&calendar), None);
+    assert_eq!(UnixTimestamp::MIN.add_business_days(i64::MAX, &calendar), None);
+    assert_eq!(UnixTimestamp::MAX.add_business_days(i64::MIN + 1, &calendar), None);
+    assert_eq!(ymd(2020, 10, 7).next_business_day(&BusinessCalendar::new(WeekdaySet::ALL)), None);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "bytemuck")]
+
+mod common;
+
+use bytemuck::checked::{self, CheckedCastError};
+use practicaltimestamp::UnixTimestamp;
+use common::ts;
+
+#[test]
+fn cast() {
+    let timestamps = [ts(0), ts(1_615_705_200), UnixTimestamp::MAX];
+    let seconds: &[i64] = bytemuck::cast_slice(&timestamps);
+    assert_eq!(seconds, &[0, 1_615_705_200, 253_402_300_800]);
+    assert_eq!(bytemuck::bytes_of(&timestamps[1]), &1_615_705_200i64.to_ne_bytes());
+    assert_eq!(<UnixTimestamp as bytemuck::Zeroable>::zeroed(), UnixTimestamp::MIN);
+}
+
+#[test]
+fn checked_cast() {
+    let seconds = [0i64, 1_615_705_200, 253_402_300_800];
+    assert_eq!(checked::try_cast_slice::<i64, UnixTimestamp>(&seconds), Ok(&[ts(0), ts(1_615_705_200), UnixTimestamp::MAX][..]));
+    assert_eq!(checked::try_cast_slice::<u8, UnixTimestamp>(bytemuck::cast_slice(&seconds)).map(<[_]>::len), Ok(3));
+    assert_eq!(checked::try_cast::<i64, UnixTimestamp>(1_615_705_200), Ok(ts(1_615_705_200)));
+
+    for &invalid in &[-1, 253_402_300_801, i64::MIN, i64::MAX] {
+        assert_eq!(checked::try_cast::<i64, UnixTimestamp>(invalid), Err(CheckedCastError::InvalidBitPattern), "{}", invalid);
+        assert_eq!(checked::try_cast_slice::<i64, UnixTimestamp>(&[0, invalid]), Err(CheckedCastError::InvalidBitPattern), "{}", invalid);
+    }
+    let bytes = [0u8; 7];
+    assert!(checked::try_cast_slice::<u8, UnixTimestamp>(&bytes).is_err());
+}
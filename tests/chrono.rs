@@ -0,0 +1,66 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use std::convert::TryFrom;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use practicaltimestamp::{util::Weekday, TimestampResult, UnixTimestamp};
+use common::{overflow, ts};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn date_time() {
+    const TEST_CASES: &[i64] = &[0, 951_782_400, 1_615_705_200, 253_402_300_799, 253_402_300_800];
+
+    for &timestamp in TEST_CASES {
+        let date_time = DateTime::<Utc>::from(ts(timestamp));
+        assert_eq!(date_time.timestamp(), timestamp);
+        assert_eq!(UnixTimestamp::try_from(date_time).unwrap(), ts(timestamp));
+        let naive = NaiveDateTime::from(ts(timestamp));
+        assert_eq!(naive, date_time.naive_utc());
+        assert_eq!(UnixTimestamp::try_from(naive).unwrap(), ts(timestamp));
+    }
+
+    let offset = FixedOffset::east_opt(-4 * 3_600).unwrap();
+    let new_york = DateTime::parse_from_rfc3339("2021-03-14T03:00:00.75-04:00").unwrap();
+    assert_eq!(new_york.offset(), &offset);
+    assert_eq!(UnixTimestamp::try_from(new_york).unwrap(), ts(1_615_705_200));
+
+    let before_epoch = DateTime::<Utc>::from_timestamp(-1, 500_000_000).unwrap();
+    assert_eq!(overflow(UnixTimestamp::try_from(before_epoch)), Some(-1));
+    assert_eq!(UnixTimestamp::try_from(before_epoch).unwrap_or_else(TimestampResult::unwrap), UnixTimestamp::MIN);
+    let after_max = DateTime::<Utc>::from_timestamp(253_402_300_801, 0).unwrap();
+    assert_eq!(overflow(UnixTimestamp::try_from(after_max.naive_utc())), Some(253_402_300_801));
+    assert_eq!(UnixTimestamp::try_from(after_max).unwrap_or_else(TimestampResult::unwrap), UnixTimestamp::MAX);
+}
+
+#[test]
+fn naive_date() {
+    const TEST_CASES: &[(i64, (i32, u32, u32))] = &[
+        (0, (1970, 1, 1)),
+        (951_782_400, (2000, 2, 29)),
+        (1_615_705_200, (2021, 3, 14)),
+        (253_402_214_400, (9999, 12, 31)),
+    ];
+
+    for &(timestamp, (year, month, day)) in TEST_CASES {
+        assert_eq!(NaiveDate::from(ts(timestamp)), date(year, month, day));
+        assert_eq!(UnixTimestamp::try_from(date(year, month, day)).unwrap(), ts(timestamp).midnight());
+    }
+    assert_eq!(UnixTimestamp::try_from(date(10_000, 1, 1)).unwrap(), UnixTimestamp::MAX);
+    assert_eq!(overflow(UnixTimestamp::try_from(date(1969, 12, 31))), Some(-86_400));
+    assert_eq!(overflow(UnixTimestamp::try_from(date(10_000, 1, 2))), Some(253_402_387_200));
+}
+
+#[test]
+fn weekday() {
+    for (i, &weekday) in Weekday::ALL.iter().enumerate() {
+        let chrono_weekday = chrono::Weekday::from(weekday);
+        assert_eq!(chrono_weekday.num_days_from_monday() as usize, i);
+        assert_eq!(Weekday::from(chrono_weekday), weekday);
+    }
+    assert_eq!(chrono::Weekday::from(ts(1_615_705_200).weekday()), chrono::Weekday::Sun);
+}
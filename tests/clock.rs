@@ -0,0 +1,54 @@
+mod common;
+
+use practicaltimestamp::{Clock, FixedClock, MockClock, UnixTimestamp};
+use common::ts;
+
+// Stands in for code under test that depends on the current time
+fn is_expired(clock: impl Clock, deadline: UnixTimestamp) -> bool {
+    clock.now() >= deadline
+}
+
+#[test]
+fn fixed_clock() {
+    let clock = FixedClock::new(ts(1_600_000_000));
+    assert_eq!(clock.now(), ts(1_600_000_000));
+    assert!(is_expired(clock, ts(1_600_000_000)));
+    assert!(!is_expired(clock, ts(1_600_000_001)));
+}
+
+#[test]
+fn mock_clock() {
+    let clock = MockClock::new(ts(1_600_000_000));
+    assert!(!is_expired(&clock, ts(1_600_000_060)));
+    clock.advance(60);
+    assert!(is_expired(&clock, ts(1_600_000_060)));
+    clock.advance(-120);
+    assert_eq!(clock.now(), ts(1_599_999_940));
+    clock.set(ts(0));
+    assert_eq!(clock.now(), ts(0));
+    clock.advance(-1);
+    assert_eq!(clock.now(), UnixTimestamp::MIN);
+    clock.advance(i64::MAX);
+    assert_eq!(clock.now(), UnixTimestamp::MAX);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn shared_clocks() {
+    use practicaltimestamp::SystemClock;
+    use std::{sync::Arc, thread};
+
+    let clock = Arc::new(MockClock::new(ts(0)));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let clock = Arc::clone(&clock);
+            thread::spawn(move || (0..1_000).for_each(|_| clock.advance(1)))
+        })
+        .collect();
+    threads.into_iter().for_each(|thread| thread.join().unwrap());
+    assert_eq!(clock.now(), ts(4_000));
+
+    let clocks: Vec<Box<dyn Clock>> = vec![Box::new(SystemClock), Box::new(FixedClock::new(ts(0)))];
+    assert!(clocks[0].now() >= ts(1_600_000_000));
+    assert_eq!(clocks[1].now(), ts(0));
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use practicaltimestamp::{
+    codec::{cbor, msgpack},
+    CodecError,
+    UnixTimestamp,
+};
+use common::ts;
+
+type Decoded = Result<(i64, usize), CodecError>;
+
+#[test]
+fn msgpack_encode() {
+    const TEST_CASES: &[(i64, &[u8])] = &[
+        (0, b"\xd6\xff\x00\x00\x00\x00"),
+        (1_615_705_200, b"\xd6\xff\x60\x4d\xb4\x70"),
+        (4_294_967_295, b"\xd6\xff\xff\xff\xff\xff"),
+        (4_294_967_296, b"\xd7\xff\x00\x00\x00\x01\x00\x00\x00\x00"),
+        (17_179_869_183, b"\xd7\xff\x00\x00\x00\x03\xff\xff\xff\xff"),
+        (17_179_869_184, b"\xc7\x0c\xff\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00"),
+        (253_402_300_800, b"\xc7\x0c\xff\x00\x00\x00\x00\x00\x00\x00\x3a\xff\xf4\x41\x80"),
+    ];
+
+    for &(timestamp, expected) in TEST_CASES {
+        let mut buffer = [0; msgpack::MAX_ENCODED_LEN];
+        let len = msgpack::encode(ts(timestamp), &mut buffer).unwrap();
+        assert_eq!(&buffer[..len], expected, "{}", timestamp);
+        assert_eq!(msgpack::decode(expected), Ok((ts(timestamp), len)), "{}", timestamp);
+        assert_eq!(msgpack::encode(ts(timestamp), &mut buffer[..len - 1]), Err(CodecError::BufferTooSmall));
+    }
+}
+
+#[test]
+fn msgpack_decode() {
+    const TEST_CASES: &[(&[u8], Decoded)] = &[
+        // Nanoseconds are truncated, and trailing bytes are left unread
+        (b"\xd7\xff\x77\x35\x94\x00\x00\x00\x00\x01\xc0", Ok((1, 10))),
+        (b"\xc7\x0c\xff\x3b\x9a\xc9\xff\x00\x00\x00\x00\x60\x4d\xb4\x70", Ok((1_615_705_200, 15))),
+        (b"\xd7\xff\xee\x6b\x28\x00\x00\x00\x00\x01", Err(CodecError::InvalidNanos(1_000_000_000))),
+        (b"\xc7\x0c\xff\x3b\x9a\xca\x00\x00\x00\x00\x00\x00\x00\x00\x00", Err(CodecError::InvalidNanos(1_000_000_000))),
+        (b"\xc7\x0c\xff\x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff", Err(CodecError::OutOfRange(-1))),
+        (b"\xc7\x0c\xff\x00\x00\x00\x00\x00\x00\x00\x3a\xff\xf4\x41\x81", Err(CodecError::OutOfRange(253_402_300_801))),
+        (b"\xd6\x01\x00\x00\x00\x00", Err(CodecError::InvalidFormat)),
+        (b"\xc7\x08\xff\x00\x00\x00\x00\x00\x00\x00\x00", Err(CodecError::InvalidFormat)),
+        (b"\xce\x00\x00\x00\x00", Err(CodecError::InvalidFormat)),
+        (b"\xd6\xff\x00\x00\x00", Err(CodecError::UnexpectedEof)),
+        (b"", Err(CodecError::UnexpectedEof)),
+    ];
+
+    for &(bytes, expected) in TEST_CASES {
+        assert_eq!(msgpack::decode(bytes), expected.map(|(timestamp, len)| (ts(timestamp), len)), "{:x?}", bytes);
+    }
+}
+
+#[test]
+fn cbor_encode_epoch() {
+    const TEST_CASES: &[(i64, &[u8])] = &[
+        (0, b"\xc1\x00"),
+        (23, b"\xc1\x17"),
+        (24, b"\xc1\x18\x18"),
+        (65_535, b"\xc1\x19\xff\xff"),
+        (1_363_896_240, b"\xc1\x1a\x51\x4b\x67\xb0"),
+        (253_402_300_800, b"\xc1\x1b\x00\x00\x00\x3a\xff\xf4\x41\x80"),
+    ];
+
+    for &(timestamp, expected) in TEST_CASES {
+        let mut buffer = [0; cbor::MAX_EPOCH_LEN];
+        let len = cbor::encode_epoch(ts(timestamp), &mut buffer).unwrap();
+        assert_eq!(&buffer[..len], expected, "{}", timestamp);
+        assert_eq!(cbor::decode(expected), Ok((ts(timestamp), len)), "{}", timestamp);
+        assert_eq!(cbor::encode_epoch(ts(timestamp), &mut buffer[..len - 1]), Err(CodecError::BufferTooSmall));
+    }
+}
+
+#[test]
+fn cbor_encode_rfc3339() {
+    const TEST_CASES: &[(i64, &[u8])] = &[
+        (0, b"\xc0\x741970-01-01T00:00:00Z"),
+        (1_363_896_240, b"\xc0\x742013-03-21T20:04:00Z"),
+        (253_402_300_799, b"\xc0\x749999-12-31T23:59:59Z"),
+    ];
+
+    for &(timestamp, expected) in TEST_CASES {
+        let mut buffer = [0; cbor::RFC3339_LEN];
+        assert_eq!(cbor::encode_rfc3339(ts(timestamp), &mut buffer), Ok(cbor::RFC3339_LEN), "{}", timestamp);
+        assert_eq!(&buffer[..], expected, "{}", timestamp);
+        assert_eq!(cbor::decode(expected), Ok((ts(timestamp), cbor::RFC3339_LEN)), "{}", timestamp);
+    }
+    let mut buffer = [0; cbor::RFC3339_LEN];
+    assert_eq!(cbor::encode_rfc3339(UnixTimestamp::MAX, &mut buffer), Err(CodecError::OutOfRange(253_402_300_800)));
+    assert_eq!(cbor::encode_rfc3339(UnixTimestamp::MIN, &mut buffer[..21]), Err(CodecError::BufferTooSmall));
+}
+
+#[test]
+fn cbor_decode() {
+    const TEST_CASES: &[(&[u8], Decoded)] = &[
+        // Arguments that aren't the shortest are accepted
+        (b"\xd8\x01\x1b\x00\x00\x00\x00\x51\x4b\x67\xb0", Ok((1_363_896_240, 11))),
+        (b"\xc1\xf9\x3c\x00", Ok((1, 4))),
+        (b"\xc1\xf9\x3e\x00", Ok((1, 4))),
+        (b"\xc1\xfa\x4e\xa2\x96\xcf", Ok((1_363_896_192, 6))),
+        (b"\xc1\xfb\x41\xd4\x52\xd9\xec\x20\x00\x00", Ok((1_363_896_240, 10))),
+        (b"\xc0\x78\x1d2013-03-21T15:04:00.999-05:00", Ok((1_363_896_240, 32))),
+        (b"\xc0\x742016-12-31T23:59:60Z", Ok((1_483_228_800, 22))),
+        (b"\xc1\x20", Err(CodecError::OutOfRange(-1))),
+        (b"\xc1\x3b\xff\xff\xff\xff\xff\xff\xff\xff", Err(CodecError::OutOfRange(i64::MIN))),
+        (b"\xc1\x1b\xff\xff\xff\xff\xff\xff\xff\xff", Err(CodecError::OutOfRange(i64::MAX))),
+        (b"\xc1\xfb\xbf\xe0\x00\x00\x00\x00\x00\x00", Err(CodecError::OutOfRange(-1))),
+        (b"\xc1\xf9\x7c\x00", Err(CodecError::OutOfRange(i64::MAX))),
+        (b"\xc1\xfb\xff\xf0\x00\x00\x00\x00\x00\x00", Err(CodecError::OutOfRange(i64::MIN))),
+        (b"\xc1\xf9\x7e\x00", Err(CodecError::InvalidFormat)),
+        (b"\xc1\x74", Err(CodecError::InvalidFormat)),
+        (b"\xc0\x1a\x51\x4b\x67\xb0", Err(CodecError::InvalidFormat)),
+        (b"\xc0\x742013-03-21X20:04:00Z", Err(CodecError::InvalidFormat)),
+        (b"\xc0\x742021-03-14T12:34:60Z", Err(CodecError::InvalidFormat)),
+        (b"\xc2\x00", Err(CodecError::InvalidFormat)),
+        (b"\x1a\x51\x4b\x67\xb0", Err(CodecError::InvalidFormat)),
+        (b"\xc1\x1f", Err(CodecError::InvalidFormat)),
+        (b"\xc0\x742013-03-21T20:04:00", Err(CodecError::UnexpectedEof)),
+        (b"\xc1\x1a\x51\x4b", Err(CodecError::UnexpectedEof)),
+        (b"\xc0\x7b\xff\xff\xff\xff\xff\xff\xff\xff", Err(CodecError::UnexpectedEof)),
+    ];
+
+    for &(bytes, expected) in TEST_CASES {
+        assert_eq!(cbor::decode(bytes), expected.map(|(timestamp, len)| (ts(timestamp), len)), "{:x?}", bytes);
+    }
+}
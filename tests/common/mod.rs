@@ -0,0 +1,55 @@
+// Shared by the integration tests, each of which uses only some of these
+#![allow(dead_code)]
+
+use practicaltimestamp::{TimestampResult, UnixTimestamp};
+
+pub fn ts(timestamp: i64) -> UnixTimestamp {
+    UnixTimestamp::from_unix_timestamp(timestamp).unwrap()
+}
+
+// The overflowed seconds of a failed conversion from another date and time type
+pub fn overflow(result: Result<UnixTimestamp, TimestampResult>) -> Option<i64> {
+    match result {
+        Err(TimestampResult::OverflowErr(value)) => Some(value),
+        _ => None,
+    }
+}
+
+// SplitMix64, so failures can be reproduced
+pub struct Random(pub u64);
+
+impl Random {
+    pub fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+// The edge cases that the `Arbitrary` implementations make more likely
+#[derive(Debug, Default)]
+pub struct EdgeCases {
+    min: bool,
+    max: bool,
+    leap_day: bool,
+    new_year: bool,
+    end_of_day: bool,
+}
+
+impl EdgeCases {
+    pub fn record(&mut self, timestamp: UnixTimestamp) {
+        let (year, month, day) = timestamp.to_year_month_day();
+        let seconds = timestamp.seconds_since_midnight();
+        self.min |= timestamp == UnixTimestamp::MIN;
+        self.max |= timestamp == UnixTimestamp::MAX;
+        self.leap_day |= (month, day) == (2, 29);
+        self.new_year |= (1971..=9999).contains(&year) && ((month, day, seconds) == (1, 1, 0) || (month, day, seconds) == (12, 31, 86_399));
+        self.end_of_day |= seconds == 86_399;
+    }
+
+    pub fn all(&self) -> bool {
+        self.min && self.max && self.leap_day && self.new_year && self.end_of_day
+    }
+}
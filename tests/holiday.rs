@@ -0,0 +1,117 @@
+use practicaltimestamp::{HolidayCalendar, HolidayDate, HolidayRule, Observance, UnixTimestamp, util::Weekday};
+
+type YearMonthDay = (u16, u8, u8);
+
+const UNITED_STATES_FEDERAL: &[HolidayRule] = &[
+    HolidayRule::new("New Year's Day", HolidayDate::Fixed { month: 1, day: 1 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Martin Luther King Jr. Day", HolidayDate::NthWeekday { month: 1, weekday: Weekday::MONDAY, nth: 3 }),
+    HolidayRule::new("Washington's Birthday", HolidayDate::NthWeekday { month: 2, weekday: Weekday::MONDAY, nth: 3 }),
+    HolidayRule::new("Memorial Day", HolidayDate::LastWeekday { month: 5, weekday: Weekday::MONDAY }),
+    HolidayRule::new("Juneteenth", HolidayDate::Fixed { month: 6, day: 19 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Independence Day", HolidayDate::Fixed { month: 7, day: 4 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Labor Day", HolidayDate::NthWeekday { month: 9, weekday: Weekday::MONDAY, nth: 1 }),
+    HolidayRule::new("Columbus Day", HolidayDate::NthWeekday { month: 10, weekday: Weekday::MONDAY, nth: 2 }),
+    HolidayRule::new("Veterans Day", HolidayDate::Fixed { month: 11, day: 11 }).with_observance(Observance::NearestWeekday),
+    HolidayRule::new("Thanksgiving Day", HolidayDate::NthWeekday { month: 11, weekday: Weekday::THURSDAY, nth: 4 }),
+    HolidayRule::new("Christmas Day", HolidayDate::Fixed { month: 12, day: 25 }).with_observance(Observance::NearestWeekday),
+];
+
+const ENGLAND_BANK_HOLIDAYS: &[HolidayRule] = &[
+    HolidayRule::new("New Year's Day", HolidayDate::Fixed { month: 1, day: 1 }).with_observance(Observance::NextWeekday),
+    HolidayRule::new("Good Friday", HolidayDate::EasterOffset(-2)),
+    HolidayRule::new("Easter Monday", HolidayDate::EasterOffset(1)),
+    HolidayRule::new("Early May bank holiday", HolidayDate::NthWeekday { month: 5, weekday: Weekday::MONDAY, nth: 1 }),
+    HolidayRule::new("Spring bank holiday", HolidayDate::LastWeekday { month: 5, weekday: Weekday::MONDAY }),
+    HolidayRule::new("Summer bank holiday", HolidayDate::LastWeekday { month: 8, weekday: Weekday::MONDAY }),
+    HolidayRule::new("Christmas Day", HolidayDate::Fixed { month: 12, day: 25 }).with_observance(Observance::NextFreeWeekday),
+    HolidayRule::new("Boxing Day", HolidayDate::Fixed { month: 12, day: 26 }).with_observance(Observance::NextFreeWeekday),
+];
+
+fn ymd(year: u16, month: u8, day: u8) -> UnixTimestamp {
+    UnixTimestamp::from_year_month_day(year, month, day).unwrap()
+}
+
+fn holidays(calendar: HolidayCalendar<'_>, year: u16) -> Vec<YearMonthDay> {
+    calendar.holidays(year).map(|(date, _)| date.to_year_month_day()).collect()
+}
+
+#[test]
+fn date_in_year() {
+    const TEST_CASES: &[(HolidayDate, u16, Option<YearMonthDay>)] = &[
+        (HolidayDate::Fixed { month: 2, day: 29 }, 2020, Some((2020, 2, 29))),
+        (HolidayDate::Fixed { month: 2, day: 29 }, 2021, None),
+        (HolidayDate::NthWeekday { month: 11, weekday: Weekday::THURSDAY, nth: 4 }, 2020, Some((2020, 11, 26))),
+        (HolidayDate::NthWeekday { month: 9, weekday: Weekday::MONDAY, nth: 1 }, 2020, Some((2020, 9, 7))),
+        (HolidayDate::NthWeekday { month: 6, weekday: Weekday::MONDAY, nth: 5 }, 2020, Some((2020, 6, 29))),
+        (HolidayDate::NthWeekday { month: 6, weekday: Weekday::WEDNESDAY, nth: 5 }, 2020, None),
+        (HolidayDate::NthWeekday { month: 6, weekday: Weekday::TUESDAY, nth: 0 }, 2020, None),
+        (HolidayDate::LastWeekday { month: 5, weekday: Weekday::MONDAY }, 2020, Some((2020, 5, 25))),
+        (HolidayDate::LastWeekday { month: 5, weekday: Weekday::SUNDAY }, 2020, Some((2020, 5, 31))),
+        (HolidayDate::LastWeekday { month: 13, weekday: Weekday::SUNDAY }, 2020, None),
+        (HolidayDate::EasterOffset(-2), 2020, Some((2020, 4, 10))),
+        (HolidayDate::EasterOffset(60), 2020, Some((2020, 6, 11))),
+        (HolidayDate::Fixed { month: 12, day: 25 }, 1969, None),
+        (HolidayDate::EasterOffset(0), 10000, None),
+    ];
+
+    for &(date, year, expected) in TEST_CASES {
+        let rule = HolidayRule::new("", date);
+        assert_eq!(rule.date_in_year(year).map(UnixTimestamp::to_year_month_day), expected);
+    }
+}
+
+#[test]
+fn united_states_federal() {
+    let calendar = HolidayCalendar::new(UNITED_STATES_FEDERAL);
+
+    assert_eq!(holidays(calendar, 2021), [
+        (2021, 1, 1), (2021, 1, 18), (2021, 2, 15), (2021, 5, 31), (2021, 6, 18), (2021, 7, 5),
+        (2021, 9, 6), (2021, 10, 11), (2021, 11, 11), (2021, 11, 25), (2021, 12, 24), (2021, 12, 31),
+    ]);
+    // New Year's Day 2022 is observed in 2021
+    assert_eq!(holidays(calendar, 2022)[0], (2022, 1, 17));
+    assert_eq!(calendar.holiday(ymd(2021, 12, 31)).map(HolidayRule::name), Some("New Year's Day"));
+    assert_eq!(calendar.holiday(ymd(2021, 7, 5).saturating_add(3_600)).map(HolidayRule::name), Some("Independence Day"));
+    assert!(!calendar.is_holiday(ymd(2021, 7, 4)));
+    assert!(!calendar.is_holiday(ymd(2022, 1, 1)));
+    assert!(calendar.is_holiday(ymd(2020, 11, 26)));
+}
+
+#[test]
+fn england_bank_holidays() {
+    let calendar = HolidayCalendar::new(ENGLAND_BANK_HOLIDAYS);
+
+    assert_eq!(holidays(calendar, 2021), [
+        (2021, 1, 1), (2021, 4, 2), (2021, 4, 5), (2021, 5, 3), (2021, 5, 31), (2021, 8, 30), (2021, 12, 27), (2021, 12, 28),
+    ]);
+    assert_eq!(holidays(calendar, 2022)[6..], [(2022, 12, 26), (2022, 12, 27)]);
+    assert_eq!(holidays(calendar, 2023)[0], (2023, 1, 2));
+    assert_eq!(calendar.holiday(ymd(2022, 12, 27)).map(HolidayRule::name), Some("Christmas Day"));
+    assert_eq!(calendar.holiday(ymd(2021, 12, 28)).map(HolidayRule::name), Some("Boxing Day"));
+    assert!(!calendar.is_holiday(ymd(2021, 12, 25)));
+}
+
+// Each rule is shifted past all the earlier ones, which must not take exponential time
+#[test]
+fn chained_next_free_weekday() {
+    const RULES: &[HolidayRule] = &[HolidayRule::new("Holiday", HolidayDate::Fixed { month: 12, day: 25 }).with_observance(Observance::NextFreeWeekday); 20];
+    let calendar = HolidayCalendar::new(RULES);
+
+    assert_eq!(calendar.observed_date(0, 2021), Some(ymd(2021, 12, 27)));
+    assert_eq!(calendar.observed_date(19, 2021), Some(ymd(2022, 1, 21)));
+    assert_eq!(calendar.observed_date(20, 2021), None);
+    // The rest of the rules of 2020 and 2021 are observed in January
+    assert_eq!(holidays(calendar, 2021)[..2], [(2021, 1, 1), (2021, 1, 4)]);
+    assert_eq!(holidays(calendar, 2021)[16..], [(2021, 12, 27), (2021, 12, 28), (2021, 12, 29), (2021, 12, 30), (2021, 12, 31)]);
+    assert_eq!(holidays(calendar, 2022).len(), 20);
+    assert!(calendar.is_holiday(ymd(2022, 1, 21)));
+    assert!(!calendar.is_holiday(ymd(2022, 1, 24)));
+}
+
+#[test]
+fn holidays_exhausted() {
+    let mut iter = HolidayCalendar::new(ENGLAND_BANK_HOLIDAYS).holidays(2021);
+    assert_eq!(iter.by_ref().count(), 8);
+    assert_eq!(iter.next(), None);
+    assert_eq!(HolidayCalendar::new(&[]).holidays(2021).next(), None);
+}
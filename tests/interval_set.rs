@@ -0,0 +1,136 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use practicaltimestamp::{IntervalSet, UnixTimestamp};
+use std::ops::Range;
+use common::ts;
+
+type Ranges = &'static [(i64, i64)];
+
+fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+    ranges.iter().map(|&(start, end)| ts(start)..ts(end)).collect()
+}
+
+fn ranges(set: &IntervalSet) -> Vec<(i64, i64)> {
+    set.iter().map(|range| (range.start.unix_timestamp(), range.end.unix_timestamp())).collect()
+}
+
+#[test]
+fn from_iter_coalesces() {
+    const TEST_CASES: &[(Ranges, Ranges)] = &[
+        (&[], &[]),
+        (&[(5, 5)], &[]),
+        (&[(7, 3)], &[]),
+        (&[(0, 10)], &[(0, 10)]),
+        (&[(10, 20), (0, 10)], &[(0, 20)]),
+        (&[(0, 5), (3, 8), (10, 12)], &[(0, 8), (10, 12)]),
+        (&[(0, 100), (10, 20), (30, 40)], &[(0, 100)]),
+        (&[(20, 30), (0, 5), (5, 10), (11, 12)], &[(0, 10), (11, 12), (20, 30)]),
+    ];
+
+    for &(input, expected) in TEST_CASES {
+        assert_eq!(ranges(&set(input)), expected);
+    }
+}
+
+#[test]
+fn insert_remove() {
+    let mut intervals = IntervalSet::new();
+    intervals.insert(ts(10)..ts(20));
+    intervals.insert(ts(30)..ts(40));
+    assert_eq!(ranges(&intervals), [(10, 20), (30, 40)]);
+    intervals.insert(ts(20)..ts(30));
+    assert_eq!(ranges(&intervals), [(10, 40)]);
+    intervals.insert(ts(0)..ts(5));
+    intervals.insert(ts(50)..ts(45));
+    assert_eq!(ranges(&intervals), [(0, 5), (10, 40)]);
+
+    intervals.remove(ts(15)..ts(25));
+    assert_eq!(ranges(&intervals), [(0, 5), (10, 15), (25, 40)]);
+    intervals.remove(ts(3)..ts(12));
+    assert_eq!(ranges(&intervals), [(0, 3), (12, 15), (25, 40)]);
+    intervals.remove(ts(0)..ts(40));
+    assert!(intervals.is_empty());
+}
+
+#[test]
+fn contains_overlaps() {
+    let intervals = set(&[(10, 20), (30, 40)]);
+
+    for &(timestamp, expected) in &[(0, false), (10, true), (19, true), (20, false), (35, true), (40, false)] {
+        assert_eq!(intervals.contains(ts(timestamp)), expected);
+    }
+    assert!(intervals.overlaps(&(ts(15)..ts(25))));
+    assert!(intervals.overlaps(&(ts(0)..ts(11))));
+    assert!(!intervals.overlaps(&(ts(20)..ts(30))));
+    assert!(!intervals.overlaps(&(ts(40)..ts(50))));
+    assert!(!intervals.overlaps(&(ts(15)..ts(15))));
+}
+
+#[test]
+fn union_intersection_difference() {
+    const TEST_CASES: &[(Ranges, Ranges, Ranges, Ranges, Ranges)] = &[
+        // (lhs, rhs, union, intersection, difference)
+        (&[], &[(0, 10)], &[(0, 10)], &[], &[]),
+        (&[(0, 10)], &[], &[(0, 10)], &[], &[(0, 10)]),
+        (&[(0, 10)], &[(10, 20)], &[(0, 20)], &[], &[(0, 10)]),
+        (&[(0, 10)], &[(5, 15)], &[(0, 15)], &[(5, 10)], &[(0, 5)]),
+        (&[(0, 30)], &[(5, 10), (15, 20)], &[(0, 30)], &[(5, 10), (15, 20)], &[(0, 5), (10, 15), (20, 30)]),
+        (&[(0, 10), (20, 30)], &[(5, 25)], &[(0, 30)], &[(5, 10), (20, 25)], &[(0, 5), (25, 30)]),
+        (&[(0, 10), (20, 30), (40, 50)], &[(8, 42)], &[(0, 50)], &[(8, 10), (20, 30), (40, 42)], &[(0, 8), (42, 50)]),
+    ];
+
+    for &(lhs, rhs, union, intersection, difference) in TEST_CASES {
+        let (lhs, rhs) = (set(lhs), set(rhs));
+        assert_eq!(ranges(&lhs.union(&rhs)), union);
+        assert_eq!(ranges(&(&rhs | &lhs)), union);
+        assert_eq!(ranges(&lhs.intersection(&rhs)), intersection);
+        assert_eq!(ranges(&(&rhs & &lhs)), intersection);
+        assert_eq!(ranges(&lhs.difference(&rhs)), difference);
+        assert_eq!(&lhs - &rhs, lhs.intersection(&rhs.complement(UnixTimestamp::MIN..UnixTimestamp::MAX)));
+    }
+}
+
+#[test]
+fn complement() {
+    let intervals = set(&[(10, 20), (30, 40)]);
+    assert_eq!(ranges(&intervals.complement(ts(0)..ts(50))), [(0, 10), (20, 30), (40, 50)]);
+    assert_eq!(ranges(&intervals.complement(ts(15)..ts(35))), [(20, 30)]);
+    assert_eq!(ranges(&intervals.complement(ts(10)..ts(20))), []);
+    assert_eq!(ranges(&IntervalSet::new().complement(ts(0)..ts(5))), [(0, 5)]);
+    assert_eq!(intervals.complement(UnixTimestamp::MIN..UnixTimestamp::MAX).complement(UnixTimestamp::MIN..UnixTimestamp::MAX), intervals);
+}
+
+#[test]
+fn matches_pointwise_set_operations() {
+    const DOMAIN: Range<i64> = 0..64;
+    let sets = [
+        set(&[(0, 4), (8, 16), (17, 20), (40, 64)]),
+        set(&[(2, 9), (12, 13), (16, 17), (30, 50)]),
+        set(&[(1, 2), (3, 4), (5, 6), (20, 40), (63, 64)]),
+        set(&[(0, 64)]),
+        set(&[]),
+    ];
+
+    for lhs in &sets {
+        for rhs in &sets {
+            let (union, intersection, difference) = (lhs | rhs, lhs & rhs, lhs - rhs);
+            for timestamp in DOMAIN.map(ts) {
+                let (l, r) = (lhs.contains(timestamp), rhs.contains(timestamp));
+                assert_eq!(union.contains(timestamp), l || r);
+                assert_eq!(intersection.contains(timestamp), l && r);
+                assert_eq!(difference.contains(timestamp), l && !r);
+            }
+        }
+    }
+}
+
+#[test]
+fn total_seconds_bounds() {
+    let intervals = set(&[(10, 20), (30, 45)]);
+    assert_eq!(intervals.total_seconds(), 25);
+    assert_eq!(intervals.bounds(), Some(ts(10)..ts(45)));
+    assert_eq!(IntervalSet::new().bounds(), None);
+    assert_eq!(IntervalSet::from(UnixTimestamp::MIN..UnixTimestamp::MAX).total_seconds(), UnixTimestamp::MAX.unix_timestamp());
+}
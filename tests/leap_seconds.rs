@@ -0,0 +1,161 @@
+mod common;
+
+use practicaltimestamp::{GpsTimestamp, LeapSecond, LeapSecondTable, TaiTimestamp, UnixTimestamp, UtcTimestamp};
+#[cfg(feature = "alloc")]
+use practicaltimestamp::{LeapSecondList, ParseLeapSecondListError};
+use common::ts;
+
+const fn utc(year: u16, month: u8, day: u8, seconds: i64) -> i64 {
+    UnixTimestamp::from_year_month_day(year, month, day).unwrap().unix_timestamp() + seconds
+}
+
+#[test]
+fn tai_offset() {
+    const TEST_CASES: &[(i64, Option<i32>)] = &[
+        (utc(1971, 12, 31, 86_399), None),
+        (utc(1972, 1, 1, 0), Some(10)),
+        (utc(1972, 6, 30, 86_399), Some(10)),
+        (utc(1972, 7, 1, 0), Some(11)),
+        (utc(2016, 12, 31, 86_399), Some(36)),
+        (utc(2017, 1, 1, 0), Some(37)),
+        (utc(2100, 1, 1, 0), Some(37)),
+    ];
+
+    for &(timestamp, tai_offset) in TEST_CASES {
+        assert_eq!(LeapSecondTable::BUILTIN.tai_offset(ts(timestamp)), tai_offset, "{}", timestamp);
+    }
+    assert!(LeapSecondTable::BUILTIN.has_leap_second(ts(utc(2016, 12, 31, 0))));
+    assert!(LeapSecondTable::BUILTIN.has_leap_second(ts(utc(1972, 6, 30, 43_200))));
+    assert!(!LeapSecondTable::BUILTIN.has_leap_second(ts(utc(2017, 1, 1, 0))));
+    assert!(!LeapSecondTable::BUILTIN.has_leap_second(ts(utc(1971, 12, 31, 0))));
+}
+
+#[test]
+fn tai() {
+    // 2016-12-31T23:59:59Z, 23:59:60Z and 2017-01-01T00:00:00Z on consecutive TAI seconds
+    let before = TaiTimestamp::from_utc(ts(utc(2016, 12, 31, 86_399)), &LeapSecondTable::BUILTIN).unwrap();
+    assert_eq!(before.seconds(), utc(2016, 12, 31, 86_399) + 36);
+    let displayed: Vec<_> = (0..3).map(|i| TaiTimestamp::new(before.seconds() + i).to_utc(&LeapSecondTable::BUILTIN).unwrap().to_string()).collect();
+    assert_eq!(displayed, ["2016-12-31T23:59:59Z", "2016-12-31T23:59:60Z", "2017-01-01T00:00:00Z"]);
+
+    let leap_second = UtcTimestamp::checked_leap_second(ts(utc(2016, 12, 31, 0)), &LeapSecondTable::BUILTIN).unwrap();
+    assert!(leap_second.is_leap_second());
+    assert_eq!(leap_second.timestamp().unix_timestamp(), utc(2016, 12, 31, 86_399));
+    assert_eq!(leap_second.seconds_since_midnight(), 86_400);
+    assert_eq!(leap_second.to_tai(&LeapSecondTable::BUILTIN), Some(TaiTimestamp::new(before.seconds() + 1)));
+    assert!(UtcTimestamp::from(ts(utc(2016, 12, 31, 86_399))) < leap_second);
+    assert!(leap_second < UtcTimestamp::from(ts(utc(2017, 1, 1, 0))));
+    assert_eq!(UtcTimestamp::checked_leap_second(ts(utc(2017, 12, 31, 0)), &LeapSecondTable::BUILTIN), None);
+
+    for &timestamp in &[utc(1972, 1, 1, 0), utc(1990, 5, 17, 12_345), utc(2017, 1, 1, 0), utc(9999, 12, 31, 0)] {
+        let tai = TaiTimestamp::from_utc(ts(timestamp), &LeapSecondTable::BUILTIN).unwrap();
+        assert_eq!(tai.to_utc(&LeapSecondTable::BUILTIN), Some(UtcTimestamp::from(ts(timestamp))), "{}", timestamp);
+    }
+    assert_eq!(TaiTimestamp::from_utc(ts(utc(1971, 1, 1, 0)), &LeapSecondTable::BUILTIN), None);
+    assert_eq!(TaiTimestamp::new(utc(1972, 1, 1, 9)).to_utc(&LeapSecondTable::BUILTIN), None);
+    assert_eq!(UtcTimestamp::from(ts(0)).to_string(), "1970-01-01T00:00:00Z");
+}
+
+#[test]
+fn negative_leap_second() {
+    const LEAP_SECONDS: &[LeapSecond] = &[
+        LeapSecond::new(UnixTimestamp::from_year_month_day(2030, 1, 1).unwrap(), 37),
+        LeapSecond::new(UnixTimestamp::from_year_month_day(2035, 1, 1).unwrap(), 36),
+    ];
+    let table = LeapSecondTable::checked_new(LEAP_SECONDS, UnixTimestamp::MAX).unwrap();
+    let before = TaiTimestamp::from_utc(ts(utc(2034, 12, 31, 86_398)), &table).unwrap();
+    assert_eq!(TaiTimestamp::new(before.seconds() + 1).to_utc(&table), Some(UtcTimestamp::from(ts(utc(2035, 1, 1, 0)))));
+    assert!(!table.has_leap_second(ts(utc(2034, 12, 31, 0))));
+    // Smeared clocks reach the removed 23:59:59 when true time is already at midnight
+    assert_eq!(UtcTimestamp::from(ts(utc(2035, 1, 1, 0))).to_smeared(&table), Some(ts(utc(2034, 12, 31, 86_399))));
+
+    let invalid: &[&[LeapSecond]] = &[
+        &[LeapSecond::new(ts(utc(2030, 1, 1, 1)), 37)],
+        &[LEAP_SECONDS[1], LEAP_SECONDS[0]],
+        &[LEAP_SECONDS[0], LeapSecond::new(LEAP_SECONDS[1].timestamp(), 39)],
+    ];
+    for &leap_seconds in invalid {
+        assert_eq!(LeapSecondTable::checked_new(leap_seconds, UnixTimestamp::MAX), None, "{:?}", leap_seconds);
+    }
+}
+
+#[test]
+fn gps() {
+    const TEST_CASES: &[(i64, i64, i64, i64)] = &[
+        (utc(1980, 1, 6, 0), 0, 0, 0),
+        (utc(1999, 8, 22, 0), 619_315_213, 1_024, 13),
+        (utc(2017, 1, 1, 0), 1_167_264_018, 1_930, 18),
+    ];
+
+    for &(timestamp, seconds, week, time_of_week) in TEST_CASES {
+        let gps = GpsTimestamp::from_utc(ts(timestamp), &LeapSecondTable::BUILTIN).unwrap();
+        assert_eq!((gps.seconds(), gps.week(), gps.time_of_week()), (seconds, week, time_of_week), "{}", timestamp);
+        assert_eq!(GpsTimestamp::checked_from_week_time_of_week(week as u32, time_of_week as u32), Some(gps));
+        assert_eq!(gps.to_utc(&LeapSecondTable::BUILTIN), Some(UtcTimestamp::from(ts(timestamp))));
+        assert_eq!(gps.to_tai().to_gps(), gps);
+    }
+    assert_eq!(GpsTimestamp::checked_from_week_time_of_week(0, 604_800), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn parse() {
+    let list: LeapSecondList = include_str!("data/leap-seconds.list").parse().unwrap();
+    assert_eq!(list.table(), LeapSecondTable::BUILTIN);
+
+    for &s in &[
+        "",
+        "2272060800\t10\n",
+        "#@\t3991593600\n2272060800\n",
+        "#@\t3991593600\n2272060800\t10\t11\n",
+        "#@\t3991593600\n2272060801\t10\n",
+        "#@\t3991593600\n2287785600\t11\n2272060800\t10\n",
+        "#@\t3991593600\n2272060800\t10\n2287785600\t12\n",
+        "#@\tnever\n",
+        "#@\t-9223372036854775808\n",
+    ] {
+        assert_eq!(s.parse::<LeapSecondList>(), Err(ParseLeapSecondListError), "{}", s);
+    }
+    assert_eq!("#@ 3991593600\n".parse::<LeapSecondList>().unwrap().table().tai_offset(ts(0)), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_file() {
+    let list = LeapSecondList::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/leap-seconds.list")).unwrap();
+    assert_eq!(list.table().expires(), ts(utc(2026, 12, 28, 0)));
+    assert!(LeapSecondList::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo/UTC")).is_err());
+}
+
+#[test]
+fn smear() {
+    let leap_second = UtcTimestamp::checked_leap_second(ts(utc(2016, 12, 31, 0)), &LeapSecondTable::BUILTIN).unwrap();
+    let true_utc = |timestamp: i64| UtcTimestamp::from(ts(timestamp));
+    const TEST_CASES: &[(i64, i64)] = &[
+        (utc(2016, 12, 31, 43_199), utc(2016, 12, 31, 43_199)),
+        (utc(2016, 12, 31, 43_200), utc(2016, 12, 31, 43_200)),
+        // Half a second behind at the leap second
+        (utc(2016, 12, 31, 86_399), utc(2016, 12, 31, 86_398)),
+        (utc(2017, 1, 1, 0), utc(2017, 1, 1, 0)),
+        (utc(2017, 1, 1, 43_199), utc(2017, 1, 1, 43_199)),
+        (utc(2017, 1, 1, 43_200), utc(2017, 1, 1, 43_200)),
+        (utc(1970, 1, 1, 0), utc(1970, 1, 1, 0)),
+    ];
+
+    for &(timestamp, smeared) in TEST_CASES {
+        assert_eq!(true_utc(timestamp).to_smeared(&LeapSecondTable::BUILTIN), Some(ts(smeared)), "{}", timestamp);
+    }
+    assert_eq!(leap_second.to_smeared(&LeapSecondTable::BUILTIN), Some(ts(utc(2016, 12, 31, 86_399))));
+    assert_eq!(UtcTimestamp::from_smeared(ts(utc(2016, 12, 31, 86_399)), &LeapSecondTable::BUILTIN), Some(leap_second));
+    assert_eq!(UtcTimestamp::from_smeared(ts(utc(2016, 12, 31, 43_201)), &LeapSecondTable::BUILTIN), Some(true_utc(utc(2016, 12, 31, 43_202))));
+
+    // Every smeared second maps back to itself, while the first two seconds of the
+    // window share one smeared second
+    let mut previous = None;
+    for seconds in utc(2016, 12, 31, 43_000)..utc(2017, 1, 1, 43_400) {
+        let utc = UtcTimestamp::from_smeared(ts(seconds), &LeapSecondTable::BUILTIN).unwrap();
+        assert_eq!(utc.to_smeared(&LeapSecondTable::BUILTIN), Some(ts(seconds)), "{}", seconds);
+        assert!(previous < Some(utc), "{}", seconds);
+        previous = Some(utc);
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(all(feature = "libc", unix))]
+#![allow(clippy::unnecessary_cast)] // time_t is 32 bits on some targets
+
+mod common;
+
+use std::{convert::TryFrom, mem};
+use practicaltimestamp::{Clock, CoarseClock, TimestampResult, UnixTimestamp};
+use common::{overflow, ts};
+
+fn fields(tm: &libc::tm) -> [libc::c_int; 9] {
+    [tm.tm_sec, tm.tm_min, tm.tm_hour, tm.tm_mday, tm.tm_mon, tm.tm_year, tm.tm_wday, tm.tm_yday, tm.tm_isdst]
+}
+
+fn gmtime(timestamp: i64) -> libc::tm {
+    let mut tm: libc::tm = unsafe { mem::zeroed() };
+    assert!(!unsafe { libc::gmtime_r(&(timestamp as libc::time_t), &mut tm) }.is_null());
+    tm
+}
+
+const TIMESTAMPS: &[i64] = &[0, 68_169_599, 951_782_400, 1_615_705_200, 2_147_483_647, 4_107_542_399, 253_402_300_799, 253_402_300_800];
+
+#[test]
+fn timespec_timeval() {
+    for &timestamp in TIMESTAMPS {
+        let timespec = libc::timespec::try_from(ts(timestamp)).unwrap();
+        assert_eq!((timespec.tv_sec as i64, timespec.tv_nsec), (timestamp, 0));
+        assert_eq!(UnixTimestamp::try_from(timespec).unwrap(), ts(timestamp));
+        let timeval = libc::timeval::try_from(ts(timestamp)).unwrap();
+        assert_eq!((timeval.tv_sec as i64, timeval.tv_usec), (timestamp, 0));
+        assert_eq!(UnixTimestamp::try_from(timeval).unwrap(), ts(timestamp));
+    }
+
+    let mut timespec = libc::timespec::try_from(ts(1_615_705_200)).unwrap();
+    timespec.tv_nsec = 999_999_999;
+    assert_eq!(UnixTimestamp::try_from(timespec).unwrap(), ts(1_615_705_200));
+    timespec.tv_sec = -1;
+    assert_eq!(overflow(UnixTimestamp::try_from(timespec)), Some(-1));
+    let mut timeval = libc::timeval::try_from(ts(0)).unwrap();
+    timeval.tv_usec = -1;
+    assert_eq!(UnixTimestamp::try_from(timeval).unwrap_or_else(TimestampResult::unwrap), UnixTimestamp::MIN);
+}
+
+// Compared against gmtime_r and timegm of the C library
+#[test]
+fn tm() {
+    for &timestamp in TIMESTAMPS {
+        let tm = libc::tm::from(ts(timestamp));
+        assert_eq!(fields(&tm), fields(&gmtime(timestamp)), "{}", timestamp);
+        assert_eq!(UnixTimestamp::try_from(tm).unwrap(), ts(timestamp));
+    }
+
+    let mut tm = libc::tm::from(ts(1_615_705_200));
+    for &(field, value) in &[(3, 32), (4, -1), (4, 14), (0, 3_600), (2, -25), (3, 0), (5, -71), (5, 8_100)] {
+        let mut tm = tm;
+        *[&mut tm.tm_sec, &mut tm.tm_min, &mut tm.tm_hour, &mut tm.tm_mday, &mut tm.tm_mon, &mut tm.tm_year][field] = value;
+        let expected = unsafe { libc::timegm(&mut tm.clone()) } as i64;
+        let result = UnixTimestamp::try_from(tm).map(UnixTimestamp::unix_timestamp).ok().or_else(|| overflow(UnixTimestamp::try_from(tm)));
+        assert_eq!(result, Some(expected), "{:?}", (field, value));
+    }
+    tm.tm_wday = 9;
+    tm.tm_yday = -4;
+    tm.tm_isdst = 1;
+    assert_eq!(UnixTimestamp::try_from(tm).unwrap(), ts(1_615_705_200));
+}
+
+#[test]
+fn now_coarse() {
+    let before = UnixTimestamp::now();
+    let coarse = UnixTimestamp::now_coarse();
+    let clock = CoarseClock.now();
+    // Coarse clocks lag by at most a scheduler tick
+    assert!(before.unix_timestamp() - 1 <= coarse.unix_timestamp());
+    assert!(coarse <= clock && clock <= UnixTimestamp::now());
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use practicaltimestamp::{Disambiguation, LocalResult, PosixTimeZone, UnixTimestamp};
+use common::ts;
+
+// Resolved timestamps of a single, an ambiguous and a skipped local time
+type Resolved = (Option<i64>, Option<i64>, Option<i64>);
+
+#[test]
+fn resolve() {
+    let london: PosixTimeZone = "GMT0BST,M3.5.0/1,M10.5.0".parse().unwrap();
+    let single = london.checked_from_local_date_time(2021, 7, 1, 12, 0, 0).unwrap();
+    let ambiguous = london.checked_from_local_date_time(2021, 10, 31, 1, 30, 0).unwrap();
+    let gap = london.checked_from_local_date_time(2021, 3, 28, 1, 30, 0).unwrap();
+    assert_eq!(single, LocalResult::Single(ts(1_625_137_200)));
+    assert_eq!(ambiguous, LocalResult::Ambiguous(ts(1_635_640_200), ts(1_635_643_800)));
+
+    const TEST_CASES: &[(Disambiguation, Resolved)] = &[
+        (Disambiguation::Earliest, (Some(1_625_137_200), Some(1_635_640_200), None)),
+        (Disambiguation::Latest, (Some(1_625_137_200), Some(1_635_643_800), None)),
+        (Disambiguation::ShiftForward, (Some(1_625_137_200), Some(1_635_640_200), Some(1_616_895_000))),
+        (Disambiguation::Reject, (Some(1_625_137_200), None, None)),
+    ];
+
+    for &(disambiguation, (single_timestamp, ambiguous_timestamp, gap_timestamp)) in TEST_CASES {
+        for &(result, timestamp) in &[(single, single_timestamp), (ambiguous, ambiguous_timestamp), (gap, gap_timestamp)] {
+            assert_eq!(result.resolve(disambiguation).map(UnixTimestamp::unix_timestamp), timestamp, "{:?} {:?}", disambiguation, result);
+        }
+    }
+}
+
+#[test]
+fn gap() {
+    let lord_howe: PosixTimeZone = "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0".parse().unwrap();
+    let gap = match lord_howe.checked_from_local_date_time(2021, 10, 3, 2, 15, 0) {
+        Some(LocalResult::None(gap)) => gap,
+        result => panic!("{:?}", result),
+    };
+    // Clocks move from 02:00 to 02:30
+    assert_eq!(gap.transition(), ts(1_633_188_600));
+    assert_eq!((gap.offset_before().seconds(), gap.offset_after().seconds(), gap.length()), (37_800, 39_600, 1_800));
+    assert_eq!(gap.shift_forward(), Some(ts(1_633_189_500)));
+    assert_eq!(lord_howe.to_offset_timestamp(gap.shift_forward().unwrap()).seconds_since_midnight(), 2 * 3_600 + 45 * 60);
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "std")]
+
+use practicaltimestamp::{PosixTimeZone, TimeZone, UnixTimestamp};
+
+// The only test in this binary, as setting `TZ` races with any other test reading the
+// environment
+#[test]
+fn local() {
+    let posix = TimeZone::from("EST5EDT,M3.2.0,M11.1.0".parse::<PosixTimeZone>().unwrap());
+    let kolkata = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo/Asia/Kolkata");
+    let test_cases = [(kolkata, TimeZone::from_file(kolkata).unwrap()), ("EST5EDT,M3.2.0,M11.1.0", posix.clone()), (":", TimeZone::utc())];
+
+    for (tz, zone) in test_cases.iter() {
+        std::env::set_var("TZ", tz);
+        assert_eq!(&TimeZone::local().unwrap(), zone, "{}", tz);
+    }
+    std::env::set_var("TZ", "EST5EDT,M3.2.0,M11.1.0");
+    let now = UnixTimestamp::now_local().unwrap();
+    assert_eq!(now.offset(), posix.offset(now.timestamp()));
+
+    for &tz in &["/nonexistent", "EST5EDT,M3.2.0"] {
+        std::env::set_var("TZ", tz);
+        assert!(TimeZone::local().is_err(), "{}", tz);
+        assert!(UnixTimestamp::now_local().is_err(), "{}", tz);
+    }
+    std::env::remove_var("TZ");
+}
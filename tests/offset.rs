@@ -0,0 +1,120 @@
+mod common;
+
+use practicaltimestamp::{OffsetTimestamp, UnixTimestamp, UtcOffset, util::Weekday};
+use common::ts;
+
+type HourMinuteSecond = (i8, i8, i8);
+
+const IST: UtcOffset = match UtcOffset::checked_from_hms(5, 30, 0) {
+    Some(offset) => offset,
+    None => UtcOffset::UTC,
+};
+const EST: UtcOffset = match UtcOffset::checked_from_hms(-5, 0, 0) {
+    Some(offset) => offset,
+    None => UtcOffset::UTC,
+};
+
+#[test]
+fn utc_offset() {
+    const TEST_CASES: &[(HourMinuteSecond, Option<i32>)] = &[
+        ((0, 0, 0), Some(0)),
+        ((5, 30, 0), Some(19_800)),
+        ((-3, -30, 0), Some(-12_600)),
+        ((0, -30, -15), Some(-1_815)),
+        ((25, 59, 59), Some(93_599)),
+        ((-25, -59, -59), Some(-93_599)),
+        ((26, 0, 0), None),
+        ((-5, 30, 0), None),
+        ((0, 60, 0), None),
+        ((0, 0, -60), None),
+    ];
+
+    for &((h, m, s), seconds) in TEST_CASES {
+        let offset = UtcOffset::checked_from_hms(h, m, s);
+        assert_eq!(offset.map(UtcOffset::seconds), seconds);
+        if let Some(offset) = offset {
+            assert_eq!(offset.to_hms(), (h, m, s));
+        }
+    }
+    assert_eq!(UtcOffset::checked_from_seconds(93_600), None);
+    assert_eq!(UtcOffset::checked_from_seconds(-93_599), Some(UtcOffset::MIN));
+    assert!(UtcOffset::UTC.is_utc());
+    assert!(EST.is_negative());
+}
+
+#[test]
+fn utc_offset_display() {
+    const TEST_CASES: &[(i32, &str)] = &[
+        (0, "+00:00"),
+        (19_800, "+05:30"),
+        (-18_000, "-05:00"),
+        (-1_815, "-00:30:15"),
+        (93_599, "+25:59:59"),
+    ];
+
+    for &(seconds, s) in TEST_CASES {
+        assert_eq!(UtcOffset::checked_from_seconds(seconds).unwrap().to_string(), s);
+    }
+}
+
+#[test]
+fn wall_clock() {
+    // 2020-10-08 @ 20:00:00 UTC
+    let utc = OffsetTimestamp::from(ts(1_602_187_200));
+    let ist = utc.to_offset(IST);
+    let est = utc.to_offset(EST);
+
+    assert_eq!(utc.to_year_month_day(), (2020, 10, 8));
+    assert_eq!(ist.to_year_month_day(), (2020, 10, 9));
+    assert_eq!(est.to_year_month_day(), (2020, 10, 8));
+    assert_eq!(ist.to_year_ordinal(), (2020, 283));
+    assert_eq!(ist.weekday(), Weekday::FRIDAY);
+    assert_eq!(est.weekday(), Weekday::THURSDAY);
+    assert_eq!(ist.seconds_since_midnight(), 5_400);
+    assert_eq!(est.seconds_since_midnight(), 54_000);
+    assert_eq!(ist.midnight().unwrap().timestamp(), ts(1_602_181_800));
+    assert_eq!(est.midnight().unwrap().timestamp(), ts(1_602_133_200));
+    assert_eq!(ist.julian_day_number(), 2_459_132);
+    assert_eq!(ist.local_timestamp(), 1_602_207_000);
+}
+
+#[test]
+fn outside_supported_range() {
+    let est = OffsetTimestamp::new(UnixTimestamp::MIN, EST);
+    assert_eq!(est.to_year_month_day(), (1969, 12, 31));
+    assert_eq!(est.to_year_ordinal(), (1969, 365));
+    assert_eq!(est.weekday(), Weekday::WEDNESDAY);
+    assert_eq!(est.seconds_since_midnight(), 68_400);
+    assert_eq!(est.midnight(), None);
+
+    let max = OffsetTimestamp::new(UnixTimestamp::MAX, UtcOffset::MAX);
+    assert_eq!(max.to_year_month_day(), (10000, 1, 2));
+    assert_eq!(max.weekday(), Weekday::SUNDAY);
+    assert_eq!(max.checked_add(1), None);
+}
+
+#[test]
+fn from_local() {
+    let ist = OffsetTimestamp::checked_from_year_month_day(2020, 10, 9, IST).unwrap();
+    assert_eq!(ist.timestamp(), ts(1_602_181_800));
+    assert_eq!(ist.to_year_month_day(), (2020, 10, 9));
+    assert_eq!(OffsetTimestamp::checked_from_year_month_day(1970, 1, 1, IST), None);
+    assert_eq!(OffsetTimestamp::checked_from_year_month_day(2020, 2, 30, IST), None);
+    assert_eq!(OffsetTimestamp::checked_from_local_timestamp(0, EST).unwrap().timestamp(), ts(18_000));
+}
+
+#[test]
+fn ordering_by_instant() {
+    let utc = OffsetTimestamp::from(ts(1_602_187_200));
+    assert_eq!(utc, utc.to_offset(IST));
+    assert!(utc.to_offset(EST) < OffsetTimestamp::new(ts(1_602_187_201), IST));
+    assert_eq!(UnixTimestamp::from(utc.to_offset(EST)), ts(1_602_187_200));
+
+    let mut timestamps = [
+        OffsetTimestamp::new(ts(30), EST),
+        OffsetTimestamp::new(ts(10), IST),
+        OffsetTimestamp::new(ts(20), UtcOffset::UTC),
+    ];
+    timestamps.sort();
+    assert_eq!(timestamps.iter().map(|ts| ts.timestamp().unix_timestamp()).collect::<Vec<_>>(), [10, 20, 30]);
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use practicaltimestamp::{util::Weekday, ParsePosixTimeZoneError, PosixTimeZone, UnixTimestamp};
+#[cfg(feature = "alloc")]
+use practicaltimestamp::TimeZone;
+use common::ts;
+
+type OffsetIsDstAbbreviation<'a> = (i32, bool, &'a str);
+type StartEnd = (i64, i64);
+
+const fn utc(year: u16, month: u8, day: u8, seconds: i64) -> i64 {
+    UnixTimestamp::from_year_month_day(year, month, day).unwrap().unix_timestamp() + seconds
+}
+
+fn zone(s: &str) -> PosixTimeZone {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse() {
+    const TEST_CASES: &[(&str, OffsetIsDstAbbreviation<'_>, Option<OffsetIsDstAbbreviation<'_>>)] = &[
+        ("UTC0", (0, false, "UTC"), None),
+        ("IST-5:30", (19_800, false, "IST"), None),
+        ("<+0545>-5:45", (20_700, false, "+0545"), None),
+        ("<-03>3", (-10_800, false, "-03"), None),
+        ("LMT+0:17:30", (-1_050, false, "LMT"), None),
+        ("EST5EDT,M3.2.0,M11.1.0", (-18_000, false, "EST"), Some((-14_400, true, "EDT"))),
+        ("EST5EDT", (-18_000, false, "EST"), Some((-14_400, true, "EDT"))),
+        ("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0", (37_800, false, "+1030"), Some((39_600, true, "+11"))),
+        ("IST-1GMT0,M10.5.0,M3.5.0/1", (3_600, false, "IST"), Some((0, true, "GMT"))),
+        ("<-02>2<-01>,M3.5.0/-1,M10.5.0/0", (-7_200, false, "-02"), Some((-3_600, true, "-01"))),
+        ("EST5EDT,0/0,J365/25", (-18_000, false, "EST"), Some((-14_400, true, "EDT"))),
+    ];
+
+    for &(s, standard, daylight_saving) in TEST_CASES {
+        let zone = zone(s);
+        let summary = |local_time_type: &practicaltimestamp::LocalTimeType| {
+            (local_time_type.offset().seconds(), local_time_type.is_dst(), String::from(local_time_type.abbreviation()))
+        };
+        assert_eq!(summary(zone.standard()), (standard.0, standard.1, String::from(standard.2)), "{}", s);
+        assert_eq!(zone.daylight_saving().map(summary), daylight_saving.map(|(offset, is_dst, abbreviation)| (offset, is_dst, String::from(abbreviation))), "{}", s);
+    }
+}
+
+#[test]
+fn parse_invalid() {
+    for &s in &[
+        "",
+        "UT0",
+        "UTC",
+        "<UTC0",
+        "<+1>-1",
+        "UTC25",
+        "UTC0:60",
+        "UTC 0",
+        "EST5EDT,M3.2.0",
+        "EST5EDT,M3.2.0,",
+        "EST5EDT,M13.2.0,M11.1.0",
+        "EST5EDT,M3.6.0,M11.1.0",
+        "EST5EDT,M3.0.0,M11.1.0",
+        "EST5EDT,M3.2.7,M11.1.0",
+        "EST5EDT,J0,M11.1.0",
+        "EST5EDT,366,M11.1.0",
+        "EST5EDT,M3.2.0/168,M11.1.0",
+        "EST5EDT,M3.2.0,M11.1.0,",
+        "EST5EDT;M3.2.0,M11.1.0",
+    ] {
+        assert_eq!(s.parse::<PosixTimeZone>(), Err(ParsePosixTimeZoneError), "{}", s);
+    }
+}
+
+#[test]
+fn transitions() {
+    const TEST_CASES: &[(&str, u16, Option<StartEnd>)] = &[
+        ("UTC0", 2021, None),
+        ("EST5EDT,M3.2.0,M11.1.0", 2021, Some((utc(2021, 3, 14, 7 * 3_600), utc(2021, 11, 7, 6 * 3_600)))),
+        ("EST5EDT,M3.2.0,M11.1.0", 2100, Some((utc(2100, 3, 14, 7 * 3_600), utc(2100, 11, 7, 6 * 3_600)))),
+        // Week 5 is the last week, whether the month has 4 or 5 of that weekday
+        ("GMT0BST,M3.5.0/1,M10.5.0", 2021, Some((utc(2021, 3, 28, 3_600), utc(2021, 10, 31, 3_600)))),
+        ("GMT0BST,M3.5.0/1,M10.5.0", 2020, Some((utc(2020, 3, 29, 3_600), utc(2020, 10, 25, 3_600)))),
+        ("<-02>2<-01>,M3.5.0/-1,M10.5.0/0", 2023, Some((utc(2023, 3, 26, 3_600), utc(2023, 10, 29, 3_600)))),
+        // Jn never counts February 29th, n always does
+        ("STD0DST,J60/0,J365/0", 2020, Some((utc(2020, 3, 1, 0), utc(2020, 12, 31, -3_600)))),
+        ("STD0DST,59/0,365/0", 2020, Some((utc(2020, 2, 29, 0), utc(2020, 12, 31, -3_600)))),
+        // n is clamped to December 31st in common years
+        ("STD0DST,59/0,365/0", 2021, Some((utc(2021, 3, 1, 0), utc(2021, 12, 31, -3_600)))),
+        ("STD0DST,0/0,365/25", 2021, Some((utc(2021, 1, 1, 0), utc(2021, 12, 31, 24 * 3_600)))),
+        ("EST5EDT,M3.2.0,J300/-1:30", 2021, Some((utc(2021, 3, 14, 7 * 3_600), utc(2021, 10, 27, 2 * 3_600 + 1_800)))),
+        ("EST5EDT,0/0,J365/25", 2021, Some((utc(2021, 1, 1, 5 * 3_600), utc(2022, 1, 1, 5 * 3_600)))),
+    ];
+
+    for &(s, year, transitions) in TEST_CASES {
+        assert_eq!(zone(s).transitions(year), transitions, "{} {}", s, year);
+    }
+}
+
+#[test]
+fn local_time_type() {
+    const TEST_CASES: &[(&str, i64, &str)] = &[
+        ("EST5EDT,M3.2.0,M11.1.0", utc(2021, 3, 14, 7 * 3_600 - 1), "EST"),
+        ("EST5EDT,M3.2.0,M11.1.0", utc(2021, 3, 14, 7 * 3_600), "EDT"),
+        ("EST5EDT,M3.2.0,M11.1.0", utc(2021, 11, 7, 6 * 3_600 - 1), "EDT"),
+        ("EST5EDT,M3.2.0,M11.1.0", utc(2021, 11, 7, 6 * 3_600), "EST"),
+        ("EST5EDT,M3.2.0,M11.1.0", 0, "EST"),
+        ("EST5EDT,M3.2.0,M11.1.0", utc(9999, 7, 1, 0), "EDT"),
+        ("EST5EDT,M3.2.0,M11.1.0", UnixTimestamp::MAX.unix_timestamp(), "EST"),
+        ("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0", utc(2040, 1, 1, 0), "+11"),
+        ("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0", utc(2040, 7, 1, 0), "+1030"),
+        ("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0", utc(2040, 12, 31, 12 * 3_600), "+11"),
+        ("IST-1GMT0,M10.5.0,M3.5.0/1", utc(2040, 1, 1, 0), "GMT"),
+        ("IST-1GMT0,M10.5.0,M3.5.0/1", utc(2040, 7, 1, 0), "IST"),
+        ("EST5EDT,0/0,J365/25", utc(2021, 1, 1, 5 * 3_600), "EDT"),
+        ("EST5EDT,0/0,J365/25", utc(2021, 12, 31, 23 * 3_600), "EDT"),
+        ("IST-5:30", utc(2040, 7, 1, 0), "IST"),
+    ];
+
+    for &(s, timestamp, abbreviation) in TEST_CASES {
+        assert_eq!(zone(s).local_time_type(ts(timestamp)).abbreviation(), abbreviation, "{} {}", s, timestamp);
+    }
+    let new_york = zone("EST5EDT,M3.2.0,M11.1.0");
+    let local = new_york.to_offset_timestamp(ts(utc(2100, 3, 14, 7 * 3_600)));
+    assert_eq!((local.to_year_month_day(), local.seconds_since_midnight(), local.weekday()), ((2100, 3, 14), 3 * 3_600, Weekday::SUNDAY));
+}
+
+#[test]
+fn checked_from_local_date_time() {
+    let new_york = zone("EST5EDT,M3.2.0,M11.1.0");
+    type LocalDateTime = (u16, u8, u8, u8, u8, u8);
+    type EarliestLatestShiftForward = (Option<i64>, Option<i64>, Option<i64>);
+    const MAX: i64 = UnixTimestamp::MAX.unix_timestamp();
+    const TEST_CASES: &[(LocalDateTime, EarliestLatestShiftForward)] = &[
+        ((2050, 7, 1, 12, 0, 0), (Some(utc(2050, 7, 1, 16 * 3_600)), Some(utc(2050, 7, 1, 16 * 3_600)), Some(utc(2050, 7, 1, 16 * 3_600)))),
+        // Skipped by the transition to daylight saving time
+        ((2050, 3, 13, 2, 30, 0), (None, None, Some(utc(2050, 3, 13, 7 * 3_600 + 1_800)))),
+        // Repeated by the transition to standard time
+        ((2050, 11, 6, 1, 30, 0), (Some(utc(2050, 11, 6, 5 * 3_600 + 1_800)), Some(utc(2050, 11, 6, 6 * 3_600 + 1_800)), Some(utc(2050, 11, 6, 5 * 3_600 + 1_800)))),
+        ((9999, 12, 31, 19, 0, 0), (Some(MAX), Some(MAX), Some(MAX))),
+    ];
+
+    for &((year, month, day, hour, minute, second), (earliest, latest, shift_forward)) in TEST_CASES {
+        let result = new_york.checked_from_local_date_time(year, month, day, hour, minute, second).unwrap();
+        assert_eq!(result.earliest().map(UnixTimestamp::unix_timestamp), earliest);
+        assert_eq!(result.latest().map(UnixTimestamp::unix_timestamp), latest);
+        assert_eq!(result.shift_forward().map(UnixTimestamp::unix_timestamp), shift_forward);
+    }
+    assert_eq!(new_york.checked_from_local_date_time(2050, 1, 1, 24, 0, 0), None);
+    assert_eq!(new_york.checked_from_local_date_time(9999, 12, 31, 19, 0, 1), None);
+    assert_eq!(new_york.checked_from_local_date_time(1969, 12, 31, 18, 0, 0), None);
+}
+
+// The footers of these files must agree with their explicit transitions, which run until 2037
+#[test]
+#[cfg(feature = "alloc")]
+fn matches_tzif_transitions() {
+    const FILES: &[&[u8]] = &[
+        include_bytes!("data/zoneinfo/America/New_York"),
+        include_bytes!("data/zoneinfo/Europe/London"),
+        include_bytes!("data/zoneinfo/Europe/Dublin"),
+        include_bytes!("data/zoneinfo/Australia/Lord_Howe"),
+        include_bytes!("data/zoneinfo/Asia/Kolkata"),
+    ];
+
+    for &bytes in FILES {
+        let zone = TimeZone::from_tzif(bytes).unwrap();
+        let footer = zone.footer().unwrap();
+        let transitions: Vec<_> = zone.transitions().filter(|&(timestamp, _)| timestamp >= utc(2010, 1, 1, 0)).collect();
+        for window in transitions.windows(2) {
+            let (timestamp, local_time_type) = window[1];
+            assert_eq!(footer.local_time_type(ts(timestamp)), local_time_type, "{:?}", footer);
+            assert_eq!(footer.local_time_type(ts(timestamp - 1)), window[0].1, "{:?}", footer);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "proptest")]
+
+mod common;
+
+use practicaltimestamp::{util::Weekday, UnixTimestamp};
+use proptest::{
+    prelude::*,
+    strategy::ValueTree,
+    test_runner::TestRunner,
+};
+use common::EdgeCases;
+
+proptest! {
+    #[test]
+    fn in_range(timestamp in any::<UnixTimestamp>(), weekday in any::<Weekday>()) {
+        prop_assert_eq!(UnixTimestamp::checked_from_unix_timestamp(timestamp.unix_timestamp()), Some(timestamp));
+        prop_assert!(Weekday::ALL.contains(&weekday));
+    }
+}
+
+#[test]
+fn edge_cases() {
+    let mut runner = TestRunner::deterministic();
+    let mut edge_cases = EdgeCases::default();
+    let mut weekdays = [false; 7];
+    for _ in 0..1_000 {
+        edge_cases.record(any::<UnixTimestamp>().new_tree(&mut runner).unwrap().current());
+        weekdays[any::<Weekday>().new_tree(&mut runner).unwrap().current().number_days_from_monday() as usize] = true;
+    }
+    assert!(edge_cases.all(), "{:?}", edge_cases);
+    assert_eq!(weekdays, [true; 7]);
+}
+
+#[test]
+fn shrink() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = any::<UnixTimestamp>().new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), UnixTimestamp::MIN);
+}
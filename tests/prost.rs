@@ -0,0 +1,43 @@
+#![cfg(feature = "prost")]
+
+mod common;
+
+use std::convert::TryFrom;
+use practicaltimestamp::{ProstTimestampError, UnixTimestamp};
+use prost_types::Timestamp;
+use common::ts;
+
+type SecondsNanos = (i64, i32);
+
+fn proto(seconds: i64, nanos: i32) -> Timestamp {
+    Timestamp { seconds, nanos }
+}
+
+#[test]
+fn to_proto() {
+    for &timestamp in &[0, 1_615_705_200, 253_402_300_799] {
+        assert_eq!(Timestamp::try_from(ts(timestamp)), Ok(proto(timestamp, 0)));
+    }
+    assert_eq!(Timestamp::try_from(UnixTimestamp::MAX), Err(ProstTimestampError::OutOfProtoRange(253_402_300_800)));
+}
+
+#[test]
+fn from_proto() {
+    const TEST_CASES: &[(SecondsNanos, Result<i64, ProstTimestampError>)] = &[
+        ((0, 0), Ok(0)),
+        ((1_615_705_200, 999_999_999), Ok(1_615_705_200)),
+        ((253_402_300_799, 999_999_999), Ok(253_402_300_799)),
+        ((1_615_705_200, 1_000_000_000), Err(ProstTimestampError::InvalidNanos(1_000_000_000))),
+        ((1_615_705_200, -1), Err(ProstTimestampError::InvalidNanos(-1))),
+        ((253_402_300_800, 0), Err(ProstTimestampError::OutOfProtoRange(253_402_300_800))),
+        ((-62_135_596_801, 0), Err(ProstTimestampError::OutOfProtoRange(-62_135_596_801))),
+        ((-62_135_596_800, 0), Err(ProstTimestampError::OutOfRange(-62_135_596_800))),
+        ((-1, 999_999_999), Err(ProstTimestampError::OutOfRange(-1))),
+    ];
+
+    for &((seconds, nanos), expected) in TEST_CASES {
+        assert_eq!(UnixTimestamp::try_from(proto(seconds, nanos)), expected.map(ts), "{:?}", (seconds, nanos));
+    }
+    let err = UnixTimestamp::try_from(proto(0, -5)).unwrap_err();
+    assert_eq!(err.to_string(), "timestamp nanos -5 are not from 0 to 999999999");
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "quickcheck")]
+
+mod common;
+
+use practicaltimestamp::{util::Weekday, UnixTimestamp};
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+use common::{EdgeCases, ts};
+
+#[test]
+fn arbitrary() {
+    fn in_range(timestamp: UnixTimestamp) -> bool {
+        UnixTimestamp::checked_from_unix_timestamp(timestamp.unix_timestamp()) == Some(timestamp)
+    }
+    QuickCheck::new().tests(1_000).quickcheck(in_range as fn(UnixTimestamp) -> bool);
+
+    let mut g = Gen::new(100);
+    let mut edge_cases = EdgeCases::default();
+    let mut weekdays = [false; 7];
+    for _ in 0..1_000 {
+        edge_cases.record(UnixTimestamp::arbitrary(&mut g));
+        weekdays[Weekday::arbitrary(&mut g).number_days_from_monday() as usize] = true;
+    }
+    assert!(edge_cases.all(), "{:?}", edge_cases);
+    assert_eq!(weekdays, [true; 7]);
+}
+
+#[test]
+fn shrink() {
+    assert!(ts(1_615_705_200).shrink().all(|timestamp| timestamp < ts(1_615_705_200)));
+    assert_eq!(ts(1_615_705_200).shrink().next(), Some(UnixTimestamp::MIN));
+    assert!(UnixTimestamp::MAX.shrink().all(|timestamp| timestamp < UnixTimestamp::MAX));
+    assert_eq!(UnixTimestamp::MIN.shrink().count(), 0);
+
+    assert_eq!(Weekday::WEDNESDAY.shrink().collect::<Vec<_>>(), [Weekday::MONDAY, Weekday::TUESDAY]);
+    assert_eq!(Weekday::MONDAY.shrink().count(), 0);
+}
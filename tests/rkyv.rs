@@ -0,0 +1,39 @@
+#![cfg(all(feature = "rkyv", feature = "alloc"))]
+
+mod common;
+
+use practicaltimestamp::{ArchivedUnixTimestamp, UnixTimestamp};
+use rkyv::{rancor::Error, vec::ArchivedVec};
+use common::ts;
+
+#[test]
+fn round_trip() {
+    let timestamps = vec![ts(0), ts(1_615_705_200), UnixTimestamp::MAX];
+    let bytes = rkyv::to_bytes::<Error>(&timestamps).unwrap();
+    let archived = rkyv::access::<ArchivedVec<ArchivedUnixTimestamp>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 3);
+    assert_eq!(archived[1], ts(1_615_705_200));
+    assert_eq!(archived[2].unix_timestamp(), 253_402_300_800);
+    assert_eq!(archived[2].to_native(), UnixTimestamp::MAX);
+    assert_eq!(rkyv::deserialize::<Vec<UnixTimestamp>, Error>(archived).unwrap(), timestamps);
+
+    let bytes = rkyv::to_bytes::<Error>(&ts(1_615_705_200)).unwrap();
+    assert_eq!(rkyv::from_bytes::<UnixTimestamp, Error>(&bytes).unwrap(), ts(1_615_705_200));
+}
+
+#[test]
+fn validation() {
+    // An archived `i64` has the same layout, so it can stand in for a corrupt timestamp
+    for &invalid in &[-1i64, 253_402_300_801, i64::MIN] {
+        let bytes = rkyv::to_bytes::<Error>(&vec![0i64, invalid]).unwrap();
+        let err = rkyv::access::<ArchivedVec<ArchivedUnixTimestamp>, Error>(&bytes).unwrap_err();
+        assert!(err.to_string().contains(&format!("archived timestamp seconds {} are out of range of UnixTimestamp", invalid)), "{}", err);
+
+        let bytes = rkyv::to_bytes::<Error>(&invalid).unwrap();
+        assert!(rkyv::from_bytes::<UnixTimestamp, Error>(&bytes).is_err());
+        // SAFETY: the bytes were archived from an `i64`, which has the same layout
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedUnixTimestamp>(&bytes) };
+        assert_eq!(archived.unix_timestamp(), invalid);
+        assert_eq!(archived.to_native(), UnixTimestamp::from_unix_timestamp(invalid).unwrap());
+    }
+}
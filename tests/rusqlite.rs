@@ -0,0 +1,46 @@
+#![cfg(feature = "rusqlite")]
+
+mod common;
+
+use practicaltimestamp::UnixTimestamp;
+use rusqlite::{Connection, Error};
+use common::ts;
+
+#[test]
+fn round_trip() {
+    let connection = Connection::open_in_memory().unwrap();
+    connection.execute("CREATE TABLE events (created INTEGER NOT NULL)", []).unwrap();
+    for &timestamp in &[0, 1_615_705_200, 253_402_300_800] {
+        connection.execute("INSERT INTO events (created) VALUES (?1)", [ts(timestamp)]).unwrap();
+        let stored: i64 = connection.query_row("SELECT created FROM events WHERE rowid = last_insert_rowid()", [], |row| row.get(0)).unwrap();
+        assert_eq!(stored, timestamp);
+        let selected: UnixTimestamp = connection.query_row("SELECT created FROM events WHERE rowid = last_insert_rowid()", [], |row| row.get(0)).unwrap();
+        assert_eq!(selected, ts(timestamp));
+    }
+}
+
+#[test]
+fn from_sql() {
+    const TEST_CASES: &[(&str, Option<i64>)] = &[
+        ("SELECT 1615705200", Some(1_615_705_200)),
+        ("SELECT datetime(1615705200, 'unixepoch')", Some(1_615_705_200)),
+        ("SELECT date(1615705200, 'unixepoch')", Some(1_615_680_000)),
+        ("SELECT '2021-03-14 07:00:00+00'", Some(1_615_705_200)),
+        ("SELECT '2021-03-14T03:00:00.5-04:00'", Some(1_615_705_200)),
+        ("SELECT -1", None),
+        ("SELECT '2021-03-14 07:00'", None),
+        ("SELECT 1615705200.0", None),
+    ];
+
+    let connection = Connection::open_in_memory().unwrap();
+    for &(sql, expected) in TEST_CASES {
+        let selected: rusqlite::Result<UnixTimestamp> = connection.query_row(sql, [], |row| row.get(0));
+        assert_eq!(selected.ok(), expected.map(ts), "{}", sql);
+    }
+
+    let err = connection.query_row::<UnixTimestamp, _, _>("SELECT 253402300801", [], |row| row.get(0)).unwrap_err();
+    assert!(matches!(err, Error::IntegralValueOutOfRange(0, 253_402_300_801)), "{:?}", err);
+    let err = connection.query_row::<UnixTimestamp, _, _>("SELECT 'yesterday'", [], |row| row.get(0)).unwrap_err();
+    assert!(matches!(err, Error::FromSqlConversionFailure(..)), "{:?}", err);
+    assert_eq!(err.to_string(), "Conversion error from type Text at index: 0, invalid timestamp \"yesterday\"");
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use practicaltimestamp::{
+    util::{Weekday, WeekdaySet},
+    GpsTimestamp,
+    OffsetTimestamp,
+    TaiTimestamp,
+    UnixTimestamp,
+    UtcOffset,
+};
+use serde_derive::{Deserialize, Serialize};
+use common::ts;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    at: UnixTimestamp,
+    #[serde(with = "practicaltimestamp::serde::rfc3339")]
+    created: UnixTimestamp,
+    #[serde(with = "practicaltimestamp::serde::milliseconds")]
+    updated: UnixTimestamp,
+    #[serde(with = "practicaltimestamp::serde::rfc3339::option")]
+    deleted: Option<UnixTimestamp>,
+    #[serde(default, with = "practicaltimestamp::serde::milliseconds::option")]
+    expires: Option<UnixTimestamp>,
+}
+
+#[test]
+fn with_modules() {
+    const TEST_CASES: &[(i64, Option<i64>, &str)] = &[
+        (
+            1_615_705_200,
+            Some(1_615_705_201),
+            r#"{"at":1615705200,"created":"2021-03-14T07:00:00Z","updated":1615705200000,"deleted":"2021-03-14T07:00:01Z","expires":1615705201000}"#,
+        ),
+        (0, None, r#"{"at":0,"created":"1970-01-01T00:00:00Z","updated":0,"deleted":null,"expires":null}"#),
+    ];
+
+    for &(timestamp, optional, json) in TEST_CASES {
+        let event = Event { at: ts(timestamp), created: ts(timestamp), updated: ts(timestamp), deleted: optional.map(ts), expires: optional.map(ts) };
+        assert_eq!(serde_json::to_string(&event).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Event>(json).unwrap(), event);
+    }
+
+    let json = r#"{"at":1615705200,"created":"2021-03-13T23:00:00.999-08:00","updated":1615705200999,"deleted":"2021-03-14t07:00:00z"}"#;
+    let event: Event = serde_json::from_str(json).unwrap();
+    assert_eq!((event.created, event.updated, event.deleted, event.expires), (ts(1_615_705_200), ts(1_615_705_200), Some(ts(1_615_705_200)), None));
+}
+
+#[test]
+fn rfc3339() {
+    const TEST_CASES: &[(&str, Option<(i64, i32)>)] = &[
+        ("2021-03-14T03:00:00-04:00", Some((1_615_705_200, -14_400))),
+        ("2021-03-14 12:30:00+05:30", Some((1_615_705_200, 19_800))),
+        ("1972-01-01T00:00:00-00:44:30", Some((63_072_000 + 2_670, -2_670))),
+        ("2016-12-31T23:59:60Z", Some((1_483_228_800, 0))),
+        ("2016-12-31T18:59:60-05:00", Some((1_483_228_800, -18_000))),
+        ("2021-03-14T12:34:60Z", None),
+        ("2016-12-31T23:59:60-05:00", None),
+        ("9999-12-31T23:59:59Z", Some((UnixTimestamp::MAX.unix_timestamp() - 1, 0))),
+        ("1969-12-31T23:59:59Z", None),
+        ("1970-01-01T00:00:00+00:01", None),
+        ("2021-02-29T00:00:00Z", None),
+        ("2021-03-14T24:00:00Z", None),
+        ("2021-03-14T03:00:61Z", None),
+        ("2021-03-14T03:00:00.Z", None),
+        ("2021-03-14T03:00:00", None),
+        ("2021-03-14T03:00:00+26:00", None),
+        ("2021-03-14T03:00:00+0400", None),
+        ("2021-03-14T03:00:00Z ", None),
+        ("2021-3-14T03:00:00Z", None),
+    ];
+
+    for &(s, expected) in TEST_CASES {
+        let result = serde_json::from_str::<OffsetTimestamp>(&format!("\"{}\"", s)).ok();
+        assert_eq!(result.map(|timestamp| (timestamp.timestamp().unix_timestamp(), timestamp.offset().seconds())), expected, "{}", s);
+    }
+    let offset = UtcOffset::checked_from_hms(-4, 0, 0).unwrap();
+    assert_eq!(serde_json::to_string(&OffsetTimestamp::new(ts(1_615_705_200), offset)).unwrap(), r#""2021-03-14T03:00:00-04:00""#);
+    assert_eq!(serde_json::to_string(&OffsetTimestamp::from(ts(1_615_705_200))).unwrap(), r#""2021-03-14T07:00:00Z""#);
+}
+
+#[test]
+fn default_representations() {
+    assert_eq!(serde_json::to_string(&Weekday::SUNDAY).unwrap(), "7");
+    assert_eq!(serde_json::from_str::<Weekday>("1").unwrap(), Weekday::MONDAY);
+    assert!(serde_json::from_str::<Weekday>("0").is_err());
+    let weekend: WeekdaySet = "Sat,Sun".parse().unwrap();
+    assert_eq!(serde_json::to_string(&weekend).unwrap(), "96");
+    assert_eq!(serde_json::from_str::<WeekdaySet>("96").unwrap(), weekend);
+    assert!(serde_json::from_str::<WeekdaySet>("128").is_err());
+    assert_eq!(serde_json::to_string(&UtcOffset::checked_from_hms(5, 30, 0).unwrap()).unwrap(), "19800");
+    assert!(serde_json::from_str::<UtcOffset>("93600").is_err());
+    assert_eq!(serde_json::from_str::<TaiTimestamp>("37").unwrap(), TaiTimestamp::new(37));
+    assert_eq!(serde_json::to_string(&GpsTimestamp::new(18)).unwrap(), "18");
+    assert!(serde_json::from_str::<UnixTimestamp>("-1").is_err());
+    assert!(serde_json::from_str::<UnixTimestamp>("253402300801").is_err());
+}
@@ -0,0 +1,70 @@
+#![cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+
+mod common;
+
+use practicaltimestamp::UnixTimestamp;
+use common::ts;
+
+// TIMESTAMPTZ parameters in the binary protocol, as microseconds from 1/1/2000
+#[cfg(feature = "sqlx-postgres")]
+#[test]
+fn postgres_encode() {
+    use sqlx::{
+        encode::IsNull,
+        postgres::{types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo},
+        Encode,
+        Postgres,
+        Type,
+    };
+
+    const TEST_CASES: &[(i64, [u8; 8])] = &[
+        (0, [0xff, 0xfc, 0xa2, 0xfe, 0xc4, 0xc8, 0x20, 0x00]),
+        (946_684_800, [0; 8]),
+        (1_615_705_200, [0x00, 0x02, 0x60, 0x78, 0x73, 0x4a, 0x1c, 0x00]),
+        (253_402_300_800, [0x03, 0x80, 0xe7, 0x0b, 0x91, 0x3b, 0x80, 0x00]),
+    ];
+
+    for &(timestamp, expected) in TEST_CASES {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(Encode::<Postgres>::encode_by_ref(&ts(timestamp), &mut buf), Ok(IsNull::No)));
+        assert_eq!(&buf[..], &expected[..], "{}", timestamp);
+    }
+
+    assert_eq!(<UnixTimestamp as Type<Postgres>>::type_info(), PgTypeInfo::with_oid(Oid(1184)));
+    assert_eq!(UnixTimestamp::array_type_info(), PgTypeInfo::with_oid(Oid(1185)));
+    for &(oid, compatible) in &[(1082, true), (1114, true), (1184, true), (20, false), (25, false)] {
+        assert_eq!(<UnixTimestamp as Type<Postgres>>::compatible(&PgTypeInfo::with_oid(Oid(oid))), compatible, "{}", oid);
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+#[test]
+fn sqlite() {
+    use futures_executor::block_on;
+    use sqlx::{Connection, SqliteConnection};
+
+    block_on(async {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        for &timestamp in &[0, 1_615_705_200, 253_402_300_800] {
+            let selected: UnixTimestamp = sqlx::query_scalar("SELECT ?").bind(ts(timestamp)).fetch_one(&mut connection).await.unwrap();
+            assert_eq!(selected, ts(timestamp));
+            let selected: i64 = sqlx::query_scalar("SELECT ?").bind(ts(timestamp)).fetch_one(&mut connection).await.unwrap();
+            assert_eq!(selected, timestamp);
+        }
+
+        const TEST_CASES: &[(&str, Option<i64>)] = &[
+            ("SELECT 1615705200", Some(1_615_705_200)),
+            ("SELECT datetime(1615705200, 'unixepoch')", Some(1_615_705_200)),
+            ("SELECT date(1615705200, 'unixepoch')", Some(1_615_680_000)),
+            ("SELECT '2021-03-14T03:00:00.5-04:00'", Some(1_615_705_200)),
+            ("SELECT -1", None),
+            ("SELECT 253402300801", None),
+            ("SELECT 'yesterday'", None),
+        ];
+
+        for &(sql, expected) in TEST_CASES {
+            let selected: Result<UnixTimestamp, _> = sqlx::query_scalar(sql).fetch_one(&mut connection).await;
+            assert_eq!(selected.ok(), expected.map(ts), "{}", sql);
+        }
+    });
+}
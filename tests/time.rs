@@ -0,0 +1,65 @@
+#![cfg(feature = "time")]
+
+mod common;
+
+use std::convert::TryFrom;
+use practicaltimestamp::{util::Weekday, TimestampResult, UnixTimestamp};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use common::{Random, overflow, ts};
+
+fn date_time(year: i32, month: Month, day: u8, seconds: u32, milliseconds: u16, offset_hours: i8) -> OffsetDateTime {
+    let date = Date::from_calendar_date(year, month, day).unwrap();
+    let time = Time::from_hms_milli((seconds / 3_600) as u8, (seconds / 60 % 60) as u8, (seconds % 60) as u8, milliseconds).unwrap();
+    PrimitiveDateTime::new(date, time).assume_offset(UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+}
+
+#[test]
+fn conversions() {
+    const TEST_CASES: &[i64] = &[0, 951_782_400, 1_615_705_200, 253_402_300_799];
+
+    for &timestamp in TEST_CASES {
+        let date_time = OffsetDateTime::try_from(ts(timestamp)).unwrap();
+        assert_eq!(date_time.unix_timestamp(), timestamp);
+        assert_eq!(UnixTimestamp::try_from(date_time).unwrap(), ts(timestamp));
+        let primitive = PrimitiveDateTime::try_from(ts(timestamp)).unwrap();
+        assert_eq!(primitive.assume_utc(), date_time);
+        assert_eq!(UnixTimestamp::try_from(primitive).unwrap(), ts(timestamp));
+        let date = Date::try_from(ts(timestamp)).unwrap();
+        assert_eq!(date, date_time.date());
+        assert_eq!(UnixTimestamp::try_from(date).unwrap(), ts(timestamp).midnight());
+    }
+    assert!(OffsetDateTime::try_from(UnixTimestamp::MAX).is_err());
+    assert!(Date::try_from(UnixTimestamp::MAX).is_err());
+
+    assert_eq!(UnixTimestamp::try_from(date_time(2021, Month::March, 14, 3 * 3_600, 750, -4)).unwrap(), ts(1_615_705_200));
+    assert_eq!(overflow(UnixTimestamp::try_from(date_time(1969, Month::December, 31, 86_399, 500, 0))), Some(-1));
+    assert_eq!(overflow(UnixTimestamp::try_from(date_time(1970, Month::January, 1, 0, 0, 1))), Some(-3_600));
+    let primitive = PrimitiveDateTime::new(Date::from_calendar_date(1969, Month::December, 31).unwrap(), Time::MIDNIGHT);
+    assert_eq!(UnixTimestamp::try_from(primitive).unwrap_or_else(TimestampResult::unwrap), UnixTimestamp::MIN);
+}
+
+#[test]
+fn weekday() {
+    for (i, &weekday) in Weekday::ALL.iter().enumerate() {
+        let time_weekday = time::Weekday::from(weekday);
+        assert_eq!(time_weekday.number_days_from_monday() as usize, i);
+        assert_eq!(Weekday::from(time_weekday), weekday);
+    }
+}
+
+// Differential test of the calendar arithmetic against `time`
+#[test]
+fn matches_time() {
+    let mut random = Random(0x5eed);
+    let end = UnixTimestamp::MAX.unix_timestamp() as u64;
+    let edges = (0..=2).chain(end - 2..end);
+    for timestamp in edges.chain((0..100_000).map(|_| random.next() % end)) {
+        let timestamp = timestamp as i64;
+        let date_time = OffsetDateTime::from_unix_timestamp(timestamp).unwrap();
+        let (year, month, day) = ts(timestamp).to_year_month_day();
+        assert_eq!((year as i32, Month::try_from(month).unwrap(), day), date_time.to_calendar_date(), "{}", timestamp);
+        let (year, ordinal) = ts(timestamp).to_year_ordinal();
+        assert_eq!((year as i32, ordinal), date_time.to_ordinal_date(), "{}", timestamp);
+        assert_eq!(time::Weekday::from(ts(timestamp).weekday()), date_time.weekday(), "{}", timestamp);
+    }
+}
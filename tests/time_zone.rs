@@ -0,0 +1,224 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use practicaltimestamp::{LocalResult, TimeZone, TzifError, UnixTimestamp};
+use common::ts;
+
+const NEW_YORK: &[u8] = include_bytes!("data/zoneinfo/America/New_York");
+const RIGHT_NEW_YORK: &[u8] = include_bytes!("data/zoneinfo/right/America/New_York");
+const LONDON: &[u8] = include_bytes!("data/zoneinfo/Europe/London");
+const DUBLIN: &[u8] = include_bytes!("data/zoneinfo/Europe/Dublin");
+const KOLKATA: &[u8] = include_bytes!("data/zoneinfo/Asia/Kolkata");
+const LORD_HOWE: &[u8] = include_bytes!("data/zoneinfo/Australia/Lord_Howe");
+const UTC: &[u8] = include_bytes!("data/zoneinfo/UTC");
+
+const fn ts_const(timestamp: i64) -> UnixTimestamp {
+    match UnixTimestamp::checked_from_unix_timestamp(timestamp) {
+        Some(timestamp) => timestamp,
+        None => UnixTimestamp::MIN,
+    }
+}
+
+const fn local(year: u16, month: u8, day: u8, seconds: i64) -> i64 {
+    UnixTimestamp::from_year_month_day(year, month, day).unwrap().unix_timestamp() + seconds
+}
+
+// Builds a TZif file whose version 1 and version 2+ data blocks are the same
+fn tzif(version: u8, transitions: &[(i64, u8)], types: &[(i32, bool, u8)], designations: &[u8], footer: &str) -> Vec<u8> {
+    tzif_with_leap_seconds(version, transitions, types, designations, &[], footer)
+}
+
+fn tzif_with_leap_seconds(version: u8, transitions: &[(i64, u8)], types: &[(i32, bool, u8)], designations: &[u8], leap_seconds: &[(i64, i32)], footer: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let blocks: &[usize] = if version == 0 { &[4] } else { &[4, 8] };
+    for &time_len in blocks {
+        bytes.extend_from_slice(b"TZif");
+        bytes.push(version);
+        bytes.extend_from_slice(&[0; 15]);
+        for &count in &[0, 0, leap_seconds.len(), transitions.len(), types.len(), designations.len()] {
+            bytes.extend_from_slice(&(count as u32).to_be_bytes());
+        }
+        for &(timestamp, _) in transitions {
+            if time_len == 4 {
+                bytes.extend_from_slice(&(timestamp as i32).to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&timestamp.to_be_bytes());
+            }
+        }
+        bytes.extend(transitions.iter().map(|&(_, index)| index));
+        for &(offset, is_dst, index) in types {
+            bytes.extend_from_slice(&offset.to_be_bytes());
+            bytes.push(is_dst as u8);
+            bytes.push(index);
+        }
+        bytes.extend_from_slice(designations);
+        for &(occurrence, correction) in leap_seconds {
+            if time_len == 4 {
+                bytes.extend_from_slice(&(occurrence as i32).to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&occurrence.to_be_bytes());
+            }
+            bytes.extend_from_slice(&correction.to_be_bytes());
+        }
+    }
+    if version != 0 {
+        bytes.push(b'\n');
+        bytes.extend_from_slice(footer.as_bytes());
+        bytes.push(b'\n');
+    }
+    bytes
+}
+
+#[test]
+fn new_york() {
+    let zone = TimeZone::from_tzif(NEW_YORK).unwrap();
+    const TEST_CASES: &[(i64, i32, bool, &str)] = &[
+        (0, -18_000, false, "EST"),
+        (1_583_650_799, -18_000, false, "EST"),
+        (1_583_650_800, -14_400, true, "EDT"),
+        (1_604_210_399, -14_400, true, "EDT"),
+        (1_604_210_400, -18_000, false, "EST"),
+    ];
+
+    for &(timestamp, offset, is_dst, abbreviation) in TEST_CASES {
+        let local_time_type = zone.local_time_type(ts(timestamp));
+        assert_eq!(local_time_type.offset().seconds(), offset);
+        assert_eq!(local_time_type.is_dst(), is_dst);
+        assert_eq!(local_time_type.abbreviation(), abbreviation);
+    }
+    assert_eq!(zone.footer(), Some(&"EST5EDT,M3.2.0,M11.1.0".parse().unwrap()));
+    assert_eq!(zone.offset(ts(4_102_444_800)).seconds(), -18_000);
+    assert_eq!(zone.offset(ts(4_118_083_200)).seconds(), -14_400);
+    assert_eq!(zone.to_offset_timestamp(ts(1_583_650_800)).to_year_month_day(), (2020, 3, 8));
+    assert_eq!(zone.to_offset_timestamp(ts(1_583_650_800)).seconds_since_midnight(), 3 * 3_600);
+}
+
+#[test]
+fn leap_second_zone_matches_posix_zone() {
+    let zone = TimeZone::from_tzif(NEW_YORK).unwrap();
+    let right = TimeZone::from_tzif(RIGHT_NEW_YORK).unwrap();
+
+    for &timestamp in &[0, 78_796_800, 1_583_650_799, 1_583_650_800, 1_604_210_399, 1_604_210_400, 2_000_000_000] {
+        assert_eq!(right.local_time_type(ts(timestamp)), zone.local_time_type(ts(timestamp)));
+    }
+}
+
+#[test]
+fn other_zones() {
+    const TEST_CASES: &[(&[u8], i64, i32, bool, &str)] = &[
+        (UTC, 1_602_115_200, 0, false, "UTC"),
+        (KOLKATA, 1_602_115_200, 19_800, false, "IST"),
+        (LONDON, 1_578_096_000, 0, false, "GMT"),
+        (LONDON, 1_593_561_600, 3_600, true, "BST"),
+        // Irish Standard Time is the summer time, so winter is the negative daylight saving time
+        (DUBLIN, 1_578_096_000, 0, true, "GMT"),
+        (DUBLIN, 1_593_561_600, 3_600, false, "IST"),
+        (LORD_HOWE, 1_578_096_000, 39_600, true, "+11"),
+        (LORD_HOWE, 1_593_561_600, 37_800, false, "+1030"),
+    ];
+
+    for &(bytes, timestamp, offset, is_dst, abbreviation) in TEST_CASES {
+        let local_time_type = *TimeZone::from_tzif(bytes).unwrap().local_time_type(ts(timestamp));
+        assert_eq!((local_time_type.offset().seconds(), local_time_type.is_dst(), local_time_type.abbreviation()), (offset, is_dst, abbreviation));
+    }
+    assert_eq!(TimeZone::from_tzif(UTC).unwrap().offset(UnixTimestamp::MAX), TimeZone::utc().offset(UnixTimestamp::MAX));
+}
+
+#[test]
+fn checked_from_local_timestamp() {
+    let zone = TimeZone::from_tzif(NEW_YORK).unwrap();
+    const TEST_CASES: &[(i64, Option<LocalResult>)] = &[
+        (local(2020, 7, 1, 12 * 3_600), Some(LocalResult::Single(ts_const(1_593_619_200)))),
+        (local(2020, 3, 8, 3 * 3_600), Some(LocalResult::Single(ts_const(1_583_650_800)))),
+        // Repeated by the transition to standard time
+        (local(2020, 11, 1, 3_600 + 1_800), Some(LocalResult::Ambiguous(ts_const(1_604_208_600), ts_const(1_604_212_200)))),
+        (local(2020, 11, 1, 2 * 3_600), Some(LocalResult::Single(ts_const(1_604_214_000)))),
+        (local(1970, 1, 1, 0), Some(LocalResult::Single(ts_const(18_000)))),
+        (-18_001, None),
+        (i64::MIN, None),
+        (i64::MAX, None),
+    ];
+
+    for &(local_timestamp, result) in TEST_CASES {
+        assert_eq!(zone.checked_from_local_timestamp(local_timestamp), result);
+    }
+
+    // Skipped by the transition to daylight saving time
+    let gap = match zone.checked_from_local_date_time(2020, 3, 8, 2, 30, 0) {
+        Some(LocalResult::None(gap)) => gap,
+        result => panic!("{:?}", result),
+    };
+    assert_eq!((gap.transition(), gap.offset_before().seconds(), gap.offset_after().seconds(), gap.length()), (ts(1_583_650_800), -18_000, -14_400, 3_600));
+    assert_eq!(gap.shift_forward(), Some(ts(1_583_652_600)));
+    // Past the last transition, the footer decides
+    assert_eq!(zone.checked_from_local_date_time(2050, 3, 13, 2, 30, 0).map(|result| result.earliest()), Some(None));
+    assert_eq!(zone.checked_from_local_date_time(2020, 2, 30, 0, 0, 0), None);
+}
+
+#[test]
+fn versions() {
+    let transitions = &[(0, 1), (100, 0)];
+    let types = &[(0, false, 0), (3_600, true, 4)];
+    for &version in &[0, b'2', b'3', b'4'] {
+        let zone = TimeZone::from_tzif(&tzif(version, transitions, types, b"STD\0DST\0", "STD0")).unwrap();
+        assert_eq!(zone.local_time_type(ts(50)).abbreviation(), "DST");
+        assert_eq!(zone.local_time_type(ts(100)).abbreviation(), "STD");
+        assert_eq!(zone.transitions().len(), 2);
+        assert_eq!(zone.footer().copied(), if version == 0 { None } else { "STD0".parse().ok() });
+    }
+    let zone = TimeZone::from_tzif(&tzif(b'2', &[], &[(-3_600, false, 0)], b"ABC\0", "")).unwrap();
+    assert_eq!(zone.footer(), None);
+    assert_eq!(zone.offset(ts(0)).seconds(), -3_600);
+}
+
+#[test]
+fn invalid() {
+    let types = &[(0, false, 0)];
+    let mut bad_magic = tzif(b'2', &[], types, b"UTC\0", "UTC0");
+    bad_magic[0] = b'X';
+    let mut bad_version = tzif(b'2', &[], types, b"UTC\0", "UTC0");
+    bad_version[4] = b'5';
+    let truncated = &NEW_YORK[..NEW_YORK.len() - 10];
+    // A version 1 header with 0x7fff_ffff transitions and no data
+    let mut huge_timecnt = [0; 44];
+    huge_timecnt[..4].copy_from_slice(b"TZif");
+    huge_timecnt[32..36].copy_from_slice(&0x7fff_ffff_u32.to_be_bytes());
+    huge_timecnt[36..40].copy_from_slice(&1_u32.to_be_bytes());
+    huge_timecnt[40..44].copy_from_slice(&1_u32.to_be_bytes());
+
+    const NO_DESIGNATION_TERMINATOR: &[u8] = b"UTC";
+    let test_cases: &[(&[u8], TzifError)] = &[
+        (&[], TzifError::InvalidHeader),
+        (&bad_magic, TzifError::InvalidHeader),
+        (&bad_version, TzifError::UnsupportedVersion(b'5')),
+        (truncated, TzifError::InvalidFooter),
+        (&NEW_YORK[..1_000], TzifError::UnexpectedEof),
+        (&huge_timecnt, TzifError::UnexpectedEof),
+        (&tzif(b'2', &[], &[], b"UTC\0", "UTC0"), TzifError::InvalidHeader),
+        (&tzif(b'2', &[(100, 0), (100, 0)], types, b"UTC\0", "UTC0"), TzifError::InvalidTransition),
+        (&tzif(b'2', &[(100, 1)], types, b"UTC\0", "UTC0"), TzifError::InvalidTransition),
+        (&tzif(b'2', &[], &[(100_000, false, 0)], b"UTC\0", "UTC0"), TzifError::InvalidLocalTimeType),
+        (&tzif(b'2', &[], &[(0, false, 4)], b"UTC\0", "UTC0"), TzifError::InvalidLocalTimeType),
+        (&tzif(b'2', &[], types, NO_DESIGNATION_TERMINATOR, "UTC0"), TzifError::InvalidLocalTimeType),
+        (&tzif(b'2', &[], types, b"UTC\0", "UTC"), TzifError::InvalidFooter),
+        (&tzif_with_leap_seconds(b'2', &[(i64::MIN, 0)], types, b"UTC\0", &[(i64::MIN, 1)], "UTC0"), TzifError::InvalidLeapSecond),
+        // Correcting the second transition moves it before the first
+        (&tzif_with_leap_seconds(b'2', &[(100, 0), (101, 0)], types, b"UTC\0", &[(101, 2)], "UTC0"), TzifError::InvalidLeapSecond),
+    ];
+
+    for &(bytes, err) in test_cases {
+        assert_eq!(TimeZone::from_tzif(bytes), Err(err));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_zoneinfo() {
+    assert_eq!(TimeZone::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo/America/New_York")).unwrap(), TimeZone::from_tzif(NEW_YORK).unwrap());
+    assert_eq!(TimeZone::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo/UTC")).unwrap().local_time_types().len(), 1);
+
+    for &name in &["", "/etc/localtime", "../zoneinfo/UTC", "America/../UTC", "Mars/Olympus_Mons"] {
+        assert!(TimeZone::from_zoneinfo(name).is_err(), "{}", name);
+    }
+}
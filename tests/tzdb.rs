@@ -0,0 +1,96 @@
+#![cfg(feature = "tzdb")]
+
+mod common;
+
+#[cfg(feature = "alloc")]
+use practicaltimestamp::TimeZone;
+use practicaltimestamp::{LocalResult, StaticTimeZone};
+use common::ts;
+
+const NEW_YORK: Option<&StaticTimeZone> = StaticTimeZone::from_name("America/New_York");
+
+#[test]
+fn from_name() {
+    const TEST_CASES: &[(&str, Option<&str>)] = &[
+        ("America/New_York", Some("America/New_York")),
+        ("US/Eastern", Some("America/New_York")),
+        ("Europe/London", Some("Europe/London")),
+        ("GB", Some("Europe/London")),
+        ("Asia/Calcutta", Some("Asia/Kolkata")),
+        ("UTC", Some("Etc/UTC")),
+        ("america/new_york", None),
+        ("America/New_Yor", None),
+        ("America", None),
+        ("", None),
+    ];
+
+    for &(name, zone) in TEST_CASES {
+        assert_eq!(StaticTimeZone::from_name(name).map(StaticTimeZone::name), zone, "{}", name);
+    }
+    assert_eq!(NEW_YORK.map(StaticTimeZone::name), Some("America/New_York"));
+    assert!(StaticTimeZone::VERSION.starts_with("20"));
+}
+
+#[test]
+fn names() {
+    let mut count = 0;
+    for name in StaticTimeZone::names() {
+        assert_eq!(StaticTimeZone::from_name(name).map(StaticTimeZone::name), Some(name));
+        count += 1;
+    }
+    assert!(count > 400);
+}
+
+#[test]
+fn local_time_type() {
+    let zone = NEW_YORK.unwrap();
+    const TEST_CASES: &[(i64, i32, &str)] = &[
+        (0, -18_000, "EST"),
+        (1_583_650_799, -18_000, "EST"),
+        (1_583_650_800, -14_400, "EDT"),
+        (1_604_210_400, -18_000, "EST"),
+        // 2100-07-01, from the footer
+        (4_118_083_200, -14_400, "EDT"),
+        (253_402_300_800, -18_000, "EST"),
+    ];
+
+    for &(timestamp, offset, abbreviation) in TEST_CASES {
+        let local_time_type = zone.local_time_type(ts(timestamp));
+        assert_eq!((local_time_type.offset().seconds(), local_time_type.abbreviation()), (offset, abbreviation), "{}", timestamp);
+    }
+    // Only what is needed from 1970 on is kept
+    assert!(zone.transitions().all(|(timestamp, _)| timestamp > 0));
+    assert!(zone.local_time_types().iter().all(|local_time_type| local_time_type.abbreviation() != "LMT"));
+    assert_eq!(zone.footer(), &"EST5EDT,M3.2.0,M11.1.0".parse().unwrap());
+    assert_eq!(
+        zone.checked_from_local_date_time(2050, 11, 6, 1, 30, 0),
+        Some(LocalResult::Ambiguous(ts(2_551_325_400), ts(2_551_329_000)))
+    );
+}
+
+// The compiled zones must agree with the zoneinfo fixtures
+#[test]
+#[cfg(feature = "alloc")]
+fn matches_tzif() {
+    const TEST_CASES: &[(&str, &[u8])] = &[
+        ("America/New_York", include_bytes!("data/zoneinfo/America/New_York")),
+        ("Europe/London", include_bytes!("data/zoneinfo/Europe/London")),
+        ("Europe/Dublin", include_bytes!("data/zoneinfo/Europe/Dublin")),
+        ("Asia/Kolkata", include_bytes!("data/zoneinfo/Asia/Kolkata")),
+        ("Australia/Lord_Howe", include_bytes!("data/zoneinfo/Australia/Lord_Howe")),
+        ("UTC", include_bytes!("data/zoneinfo/UTC")),
+    ];
+
+    for &(name, bytes) in TEST_CASES {
+        let tzif = TimeZone::from_tzif(bytes).unwrap();
+        let zone = StaticTimeZone::from_name(name).unwrap();
+        let transitions = tzif.transitions().map(|(timestamp, _)| timestamp).filter(|&timestamp| timestamp > 0);
+        let weekly = (0..4_200_000_000).step_by(7 * 86_400 + 3_607);
+        for timestamp in transitions.flat_map(|timestamp| vec![timestamp - 1, timestamp]).chain(weekly) {
+            assert_eq!(zone.local_time_type(ts(timestamp)), tzif.local_time_type(ts(timestamp)), "{} {}", name, timestamp);
+        }
+        let converted = TimeZone::from(zone);
+        assert_eq!(converted.offset(ts(1_593_561_600)), tzif.offset(ts(1_593_561_600)));
+        assert_eq!(converted.footer(), Some(zone.footer()));
+    }
+}
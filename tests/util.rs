@@ -0,0 +1,49 @@
+use practicaltimestamp::{UnixTimestamp, util};
+
+type YearMonthDay = (u16, u8, u8);
+
+#[test]
+fn easter_sunday() {
+    const TEST_CASES: &[(u16, YearMonthDay)] = &[
+        (1970, (1970, 3, 29)),
+        (2000, (2000, 4, 23)),
+        (2008, (2008, 3, 23)),
+        (2011, (2011, 4, 24)),
+        (2019, (2019, 4, 21)),
+        (2020, (2020, 4, 12)),
+        (2024, (2024, 3, 31)),
+        (2038, (2038, 4, 25)),
+        (2285, (2285, 3, 22)),
+        (9999, (9999, 3, 28)),
+    ];
+
+    for &(year, ymd) in TEST_CASES {
+        assert_eq!(util::easter_sunday(year).to_year_month_day(), ymd);
+    }
+}
+
+#[test]
+fn easter_sunday_bounds() {
+    for year in 1970..=9999 {
+        let easter = util::easter_sunday(year);
+        let (_, ordinal) = easter.to_year_ordinal();
+        let march_22 = UnixTimestamp::from_year_month_day(year, 3, 22).unwrap().to_year_ordinal().1;
+        assert_eq!(easter.weekday(), util::Weekday::SUNDAY);
+        assert!(march_22 <= ordinal && ordinal <= march_22 + 34);
+    }
+}
+
+#[test]
+fn movable_feasts() {
+    const TEST_CASES: &[(u16, YearMonthDay, YearMonthDay, YearMonthDay)] = &[
+        (2020, (2020, 4, 10), (2020, 5, 21), (2020, 5, 31)),
+        (2024, (2024, 3, 29), (2024, 5, 9), (2024, 5, 19)),
+        (2038, (2038, 4, 23), (2038, 6, 3), (2038, 6, 13)),
+    ];
+
+    for &(year, good_friday, ascension_day, pentecost) in TEST_CASES {
+        assert_eq!(util::good_friday(year).to_year_month_day(), good_friday);
+        assert_eq!(util::ascension_day(year).to_year_month_day(), ascension_day);
+        assert_eq!(util::pentecost(year).to_year_month_day(), pentecost);
+    }
+}
@@ -1,3 +1,5 @@
+use std::convert::TryFrom;
+
 use practicaltimestamp::util::Weekday;
 
 #[test]
@@ -61,3 +63,69 @@ fn days_until() {
         assert_eq!(earlier.days_until(later), days);
     }
 }
+
+#[test]
+fn nth_next_previous() {
+    const TEST_CASES: &[(Weekday, u64, Weekday, Weekday)] = &[
+        (Weekday::MONDAY, 0, Weekday::MONDAY, Weekday::MONDAY),
+        (Weekday::MONDAY, 1, Weekday::TUESDAY, Weekday::SUNDAY),
+        (Weekday::FRIDAY, 3, Weekday::MONDAY, Weekday::TUESDAY),
+        (Weekday::SUNDAY, 7, Weekday::SUNDAY, Weekday::SUNDAY),
+        (Weekday::WEDNESDAY, 100, Weekday::FRIDAY, Weekday::MONDAY),
+        (Weekday::SATURDAY, u64::MAX, Weekday::SUNDAY, Weekday::FRIDAY),
+    ];
+
+    for &(weekday, n, next, previous) in TEST_CASES {
+        assert_eq!(weekday.nth_next(n), next);
+        assert_eq!(weekday.nth_previous(n), previous);
+    }
+}
+
+#[test]
+fn numbering() {
+    const TEST_CASES: &[(Weekday, u8, u8, u8, u8)] = &[
+        (Weekday::MONDAY, 1, 2, 0, 1),
+        (Weekday::WEDNESDAY, 3, 4, 2, 3),
+        (Weekday::SATURDAY, 6, 7, 5, 6),
+        (Weekday::SUNDAY, 7, 1, 6, 0),
+    ];
+
+    for &(weekday, from_monday, from_sunday, days_from_monday, days_from_sunday) in TEST_CASES {
+        assert_eq!(weekday.number_from_monday(), from_monday);
+        assert_eq!(weekday.number_from_sunday(), from_sunday);
+        assert_eq!(weekday.number_days_from_monday(), days_from_monday);
+        assert_eq!(weekday.number_days_from_sunday(), days_from_sunday);
+        assert_eq!(Weekday::from_iso_number(from_monday), Some(weekday));
+        assert_eq!(Weekday::try_from(from_monday), Ok(weekday));
+        assert_eq!(u8::from(weekday), from_monday);
+    }
+    assert_eq!(Weekday::from_iso_number(0), None);
+    assert_eq!(Weekday::from_iso_number(8), None);
+    assert!(Weekday::try_from(8).is_err());
+}
+
+#[test]
+fn ord() {
+    assert!(Weekday::MONDAY < Weekday::TUESDAY);
+    assert!(Weekday::SATURDAY < Weekday::SUNDAY);
+    assert_eq!(Weekday::ALL.iter().max(), Some(&Weekday::SUNDAY));
+}
+
+#[test]
+fn iter_from() {
+    assert_eq!(Weekday::MONDAY.iter_from().collect::<Vec<_>>(), Weekday::ALL);
+    assert_eq!(Weekday::SUNDAY.iter_from().take(3).collect::<Vec<_>>(), [Weekday::SUNDAY, Weekday::MONDAY, Weekday::TUESDAY]);
+    assert_eq!(Weekday::THURSDAY.iter_from().nth(6), Some(Weekday::WEDNESDAY));
+    assert_eq!(Weekday::THURSDAY.iter_from().len(), 7);
+
+    let mut iter = Weekday::SATURDAY.iter_from();
+    assert_eq!(iter.next_back(), Some(Weekday::FRIDAY));
+    assert_eq!(iter.by_ref().count(), 6);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn hash() {
+    let set: std::collections::HashSet<Weekday> = Weekday::ALL.iter().chain(Weekday::ALL.iter()).copied().collect();
+    assert_eq!(set.len(), 7);
+}
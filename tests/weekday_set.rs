@@ -0,0 +1,108 @@
+use practicaltimestamp::util::{Weekday, WeekdaySet};
+
+#[test]
+fn constants() {
+    assert_eq!(WeekdaySet::ALL, WeekdaySet::from_weekdays(&Weekday::ALL));
+    assert_eq!(WeekdaySet::WEEKDAYS, WeekdaySet::from_range(Weekday::MONDAY, Weekday::FRIDAY));
+    assert_eq!(WeekdaySet::WEEKEND, WeekdaySet::from_weekdays(&[Weekday::SATURDAY, Weekday::SUNDAY]));
+    assert_eq!(WeekdaySet::WEEKDAYS | WeekdaySet::WEEKEND, WeekdaySet::ALL);
+    assert_eq!(WeekdaySet::WEEKDAYS & WeekdaySet::WEEKEND, WeekdaySet::EMPTY);
+    assert_eq!(!WeekdaySet::WEEKDAYS, WeekdaySet::WEEKEND);
+    assert_eq!(WeekdaySet::ALL.len(), 7);
+    assert!(WeekdaySet::EMPTY.is_empty());
+    assert!(WeekdaySet::WEEKEND.is_subset(WeekdaySet::ALL));
+    assert!(!WeekdaySet::ALL.is_subset(WeekdaySet::WEEKEND));
+}
+
+#[test]
+fn bits() {
+    assert_eq!(WeekdaySet::checked_from_bits(0x7f), Some(WeekdaySet::ALL));
+    assert_eq!(WeekdaySet::checked_from_bits(0x80), None);
+    assert_eq!(WeekdaySet::from_bits_truncate(0xff), WeekdaySet::ALL);
+    assert_eq!(WeekdaySet::single(Weekday::MONDAY).bits(), 1);
+    assert_eq!(WeekdaySet::single(Weekday::SUNDAY).bits(), 0x40);
+}
+
+#[test]
+fn contains_with_without() {
+    let set = WeekdaySet::EMPTY.with(Weekday::TUESDAY).with(Weekday::SUNDAY);
+    for &weekday in &Weekday::ALL {
+        assert_eq!(set.contains(weekday), weekday == Weekday::TUESDAY || weekday == Weekday::SUNDAY);
+    }
+    assert_eq!(set.without(Weekday::TUESDAY), WeekdaySet::single(Weekday::SUNDAY));
+    assert_eq!(set.difference(WeekdaySet::WEEKEND), WeekdaySet::single(Weekday::TUESDAY));
+}
+
+#[test]
+fn from_range() {
+    const TEST_CASES: &[(Weekday, Weekday, u8)] = &[
+        (Weekday::MONDAY, Weekday::MONDAY, 0x01),
+        (Weekday::MONDAY, Weekday::SUNDAY, 0x7f),
+        (Weekday::FRIDAY, Weekday::MONDAY, 0x71),
+        (Weekday::SUNDAY, Weekday::SATURDAY, 0x7f),
+        (Weekday::TUESDAY, Weekday::MONDAY, 0x7f),
+        (Weekday::SATURDAY, Weekday::SUNDAY, 0x60),
+    ];
+
+    for &(first, last, bits) in TEST_CASES {
+        assert_eq!(WeekdaySet::from_range(first, last).bits(), bits);
+    }
+}
+
+#[test]
+fn iter() {
+    assert_eq!(WeekdaySet::ALL.iter().collect::<Vec<_>>(), Weekday::ALL);
+    assert_eq!(WeekdaySet::WEEKEND.into_iter().collect::<Vec<_>>(), [Weekday::SATURDAY, Weekday::SUNDAY]);
+    assert_eq!(WeekdaySet::EMPTY.iter().next(), None);
+    assert_eq!(WeekdaySet::WEEKDAYS.iter().len(), 5);
+    assert_eq!(Weekday::ALL.iter().copied().filter(|&weekday| weekday != Weekday::MONDAY).collect::<WeekdaySet>(), !WeekdaySet::single(Weekday::MONDAY));
+}
+
+#[test]
+fn next_previous_in_set() {
+    let sets = [
+        WeekdaySet::EMPTY,
+        WeekdaySet::ALL,
+        WeekdaySet::WEEKDAYS,
+        WeekdaySet::WEEKEND,
+        WeekdaySet::single(Weekday::WEDNESDAY),
+        WeekdaySet::from_weekdays(&[Weekday::MONDAY, Weekday::THURSDAY, Weekday::SUNDAY]),
+    ];
+
+    for &set in &sets {
+        for &weekday in &Weekday::ALL {
+            let mut next = weekday.next();
+            while !set.contains(next) && next != weekday {
+                next = next.next();
+            }
+            let mut previous = weekday.previous();
+            while !set.contains(previous) && previous != weekday {
+                previous = previous.previous();
+            }
+            let expected = |day: Weekday| if set.contains(day) { Some(day) } else { None };
+            assert_eq!(set.next_in_set_after(weekday), expected(next));
+            assert_eq!(set.previous_in_set_before(weekday), expected(previous));
+        }
+    }
+}
+
+#[test]
+fn parse() {
+    const TEST_CASES: &[(&str, Option<u8>)] = &[
+        ("Mon-Fri", Some(0x1f)),
+        ("Sat,Sun", Some(0x60)),
+        ("sat, SUNDAY", Some(0x60)),
+        ("Fri-Mon", Some(0x71)),
+        ("Mon,Wed-Thu,Sun", Some(0x4d)),
+        ("Tuesday - Tuesday", Some(0x02)),
+        ("", Some(0x00)),
+        ("Mon-", None),
+        ("Mon,,Tue", None),
+        ("Funday", None),
+        ("Mon-Tue-Wed", None),
+    ];
+
+    for &(s, bits) in TEST_CASES {
+        assert_eq!(s.parse::<WeekdaySet>().ok().map(WeekdaySet::bits), bits, "{}", s);
+    }
+}